@@ -0,0 +1,49 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, Token};
+
+use crate::CloneExprList;
+
+/// Input of the `atomic_load!` macro: an optional `<Ordering>;` prefix
+/// followed by regular clone entries
+pub(crate) struct AtomicLoad {
+    exprs: CloneExprList,
+}
+
+impl Parse for AtomicLoad {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut ordering = Ident::new("Relaxed", input.span());
+        // Any other `name;` prefix is left to the directives, e.g. `send;`
+        if let Some((ident, rest)) = input.cursor().ident()
+            && rest
+                .punct()
+                .is_some_and(|(punct, _)| punct.as_char() == ';')
+        {
+            if ["Relaxed", "Acquire", "SeqCst"].iter().any(|o| ident == o) {
+                ordering = input.parse()?;
+                let _semi: Token![;] = input.parse()?;
+            } else if ["Release", "AcqRel"].iter().any(|o| ident == o) {
+                return Err(syn::Error::new(
+                    ident.span(),
+                    format!(
+                        "`{}` is not a valid ordering for a load; expected `Relaxed`, `Acquire` or `SeqCst`",
+                        ident
+                    ),
+                ));
+            }
+        }
+        let mut exprs: CloneExprList = input.parse()?;
+        for expr in exprs.entries_mut() {
+            expr.clone_method = Ident::new("load", ordering.span());
+            expr.check_clone_method("atomic_load")?;
+            expr.clone_args = quote! { ::core::sync::atomic::Ordering::#ordering };
+        }
+        Ok(AtomicLoad { exprs })
+    }
+}
+
+impl ToTokens for AtomicLoad {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.exprs.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,106 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::visit::{self, Visit};
+use syn::{Expr, ExprClosure, Ident, Token};
+
+/// Input of the `autoclone!` macro: a closure whose captured variables are
+/// cloned right before it, so a `move` closure owns clones instead of the
+/// originals
+pub(crate) struct AutoClone {
+    closure: ExprClosure,
+    captures: Vec<Ident>,
+}
+
+impl Parse for AutoClone {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut closure: ExprClosure = input.parse().map_err(|e| {
+            syn::Error::new(
+                e.span(),
+                "autoclone! expects a closure, e.g. `autoclone!(move || ...)`",
+            )
+        })?;
+        let _trailing: Option<Token![,]> = input.parse()?;
+        if closure.capture.is_none() {
+            closure.capture = Some(Default::default());
+        }
+        let mut finder = CaptureFinder::default();
+        finder.visit_expr_closure(&closure);
+        let captures = finder
+            .candidates
+            .into_iter()
+            .filter(|ident| !finder.bound.contains(ident))
+            .collect();
+        Ok(AutoClone { closure, captures })
+    }
+}
+
+impl ToTokens for AutoClone {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let AutoClone { closure, captures } = self;
+        tokens.extend(quote! {
+            {
+                #(let #captures = #captures.clone();)*
+                #closure
+            }
+        });
+    }
+}
+
+/// Collects the variables a closure appears to capture.
+///
+/// This is a heuristic on tokens, without name resolution: a capture is a
+/// single-segment path starting with a lowercase letter that is used as a
+/// value, and any name bound by a pattern anywhere inside the closure is
+/// treated as local everywhere in it.
+#[derive(Default)]
+struct CaptureFinder {
+    /// Candidate captures in order of first use
+    candidates: Vec<Ident>,
+    /// Names bound by patterns inside the closure, including its parameters
+    bound: Vec<Ident>,
+}
+
+impl<'ast> Visit<'ast> for CaptureFinder {
+    fn visit_expr(&mut self, expr: &'ast Expr) {
+        match expr {
+            Expr::Path(path) if path.qself.is_none() => {
+                if let Some(ident) = path.path.get_ident()
+                    && is_variable(ident)
+                    && !self.candidates.contains(ident)
+                {
+                    self.candidates.push(ident.clone());
+                }
+            }
+            // The callee of `f(x)` is usually a function item, not a capture
+            Expr::Call(call) if matches!(&*call.func, Expr::Path(_)) => {
+                for arg in &call.args {
+                    self.visit_expr(arg);
+                }
+            }
+            _ => visit::visit_expr(self, expr),
+        }
+    }
+
+    fn visit_pat_ident(&mut self, pat: &'ast syn::PatIdent) {
+        self.bound.push(pat.ident.clone());
+        visit::visit_pat_ident(self, pat);
+    }
+
+    fn visit_macro(&mut self, mac: &'ast syn::Macro) {
+        // Arguments of expression-like macros such as `println!` and
+        // `assert_eq!`; other macro bodies are skipped
+        if let Ok(args) = mac.parse_body_with(Punctuated::<Expr, Token![,]>::parse_terminated) {
+            for arg in &args {
+                self.visit_expr(arg);
+            }
+        }
+    }
+}
+
+/// Returns `true` if the name looks like a local variable rather than a
+/// type, constant or `self`
+fn is_variable(ident: &Ident) -> bool {
+    let name = ident.to_string();
+    name != "self" && name.starts_with(|c: char| c.is_lowercase() || c == '_')
+}
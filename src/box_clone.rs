@@ -0,0 +1,40 @@
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream};
+use syn::{Ident, Token};
+
+use crate::CloneExprList;
+
+/// Input of the `box_clone!` macro: an optional `via <method>;` prefix
+/// followed by regular clone entries
+pub(crate) struct BoxClone {
+    exprs: CloneExprList,
+}
+
+impl Parse for BoxClone {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut method = Ident::new("box_clone", input.span());
+        if input.peek(syn::Ident) && input.peek2(syn::Ident) && input.peek3(Token![;]) {
+            let via: Ident = input.parse()?;
+            if via != "via" {
+                return Err(syn::Error::new(
+                    via.span(),
+                    "expected `via <method>;` to configure the clone method",
+                ));
+            }
+            method = input.parse()?;
+            let _semi: Token![;] = input.parse()?;
+        }
+        let mut exprs: CloneExprList = input.parse()?;
+        for expr in exprs.entries_mut() {
+            expr.clone_method = method.clone();
+            expr.check_clone_method("box_clone")?;
+        }
+        Ok(BoxClone { exprs })
+    }
+}
+
+impl ToTokens for BoxClone {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.exprs.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,34 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Token};
+
+use crate::receiver;
+
+/// Input of the `clone_arr!` macro: comma-separated source expressions
+pub(crate) struct CloneArr {
+    sources: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for CloneArr {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let sources = Punctuated::parse_terminated(input)?;
+        if sources.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "clone_arr! requires at least one expression",
+            ));
+        }
+        Ok(CloneArr { sources })
+    }
+}
+
+impl ToTokens for CloneArr {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let clones = self.sources.iter().map(|source| {
+            let source = receiver(source);
+            quote! { #source.clone() }
+        });
+        tokens.extend(quote! { [#(#clones),*] });
+    }
+}
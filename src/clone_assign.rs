@@ -0,0 +1,44 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, ExprAssign, Token};
+
+use crate::receiver;
+
+/// Input of the `clone_assign!` macro: comma-separated `<place> = <source>`
+/// assignments
+pub(crate) struct CloneAssign {
+    assignments: Vec<ExprAssign>,
+}
+
+impl Parse for CloneAssign {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let exprs = Punctuated::<Expr, Token![,]>::parse_terminated(input)?;
+        if exprs.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "clone_assign! requires at least one `<place> = <source>` assignment",
+            ));
+        }
+        let assignments = exprs
+            .into_iter()
+            .map(|expr| match expr {
+                Expr::Assign(assign) => Ok(assign),
+                expr => Err(syn::Error::new_spanned(
+                    expr,
+                    "expected an assignment `<place> = <source>`, e.g. `clone_assign!(target.field = source)`",
+                )),
+            })
+            .collect::<syn::Result<_>>()?;
+        Ok(CloneAssign { assignments })
+    }
+}
+
+impl ToTokens for CloneAssign {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        for ExprAssign { left, right, .. } in &self.assignments {
+            let right = receiver(right);
+            tokens.extend(quote! { #left = #right.clone(); });
+        }
+    }
+}
@@ -0,0 +1,28 @@
+use syn::{Expr, ItemFn, Stmt};
+
+use crate::CloneExprList;
+
+/// Expands `#[clone_captures(...)]`: the listed entries are cloned at the
+/// start of the function and the closure the body ends with becomes `move`,
+/// so it owns the clones instead of borrowing the originals.
+pub(crate) fn expand(captures: CloneExprList, mut item: ItemFn) -> syn::Result<ItemFn> {
+    let Some(Stmt::Expr(tail, None)) = item.block.stmts.last_mut() else {
+        return Err(syn::Error::new_spanned(
+            &item.sig,
+            "#[clone_captures] expects the function body to end with a closure",
+        ));
+    };
+    let Expr::Closure(closure) = tail else {
+        return Err(syn::Error::new_spanned(
+            tail,
+            "#[clone_captures] expects the function body to end with a closure",
+        ));
+    };
+    if closure.capture.is_none() {
+        closure.capture = Some(Default::default());
+    }
+
+    let clones: syn::Block = syn::parse_quote!({ #captures });
+    item.block.stmts.splice(0..0, clones.stmts);
+    Ok(item)
+}
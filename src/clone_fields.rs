@@ -0,0 +1,103 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Ident, Token};
+
+use crate::{CloneExprList, receiver};
+
+/// A field of `clone_fields!`: `[mut] name` is cloned, `!name` is excluded
+enum Field {
+    Included {
+        mutability: Option<Token![mut]>,
+        ident: Ident,
+    },
+    Excluded(Ident),
+}
+
+impl Parse for Field {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(Token![*]) {
+            let star: Token![*] = input.parse()?;
+            return Err(syn::Error::new(
+                star.span,
+                "clone_fields! cannot enumerate the fields of a struct; list the fields to clone explicitly",
+            ));
+        }
+        if input.peek(Token![!]) {
+            let _bang: Token![!] = input.parse()?;
+            return Ok(Field::Excluded(input.parse()?));
+        }
+        Ok(Field::Included {
+            mutability: input.parse()?,
+            ident: input.parse()?,
+        })
+    }
+}
+
+/// Input of the `clone_fields!` macro: `source => a, b, !c` clones the named
+/// fields `a` and `b` into bindings of the same name and skips `c`.
+///
+/// Excluded fields are still checked to exist, so the list stays accurate
+/// when the struct changes.
+pub(crate) struct CloneFields {
+    exprs: CloneExprList,
+    /// Never-called closures naming the excluded fields
+    checks: proc_macro2::TokenStream,
+}
+
+impl Parse for CloneFields {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let source: Expr = input.parse().map_err(|e| {
+            syn::Error::new(
+                e.span(),
+                "expected `<struct> => field, field, !excluded, ...`",
+            )
+        })?;
+        let _arrow: Token![=>] = input.parse()?;
+        let fields: Punctuated<Field, Token![,]> = Punctuated::parse_terminated(input)?;
+        let base = receiver(&source);
+        let mut entries = Vec::new();
+        let mut checks = proc_macro2::TokenStream::new();
+        for field in &fields {
+            match field {
+                Field::Included { mutability, ident } => {
+                    if excludes(&fields, ident) {
+                        return Err(syn::Error::new(
+                            ident.span(),
+                            format!("field `{}` is both cloned and excluded", ident),
+                        ));
+                    }
+                    entries.push(quote! { #mutability #base.#ident });
+                }
+                Field::Excluded(ident) => checks.extend(quote! {
+                    let _ = || {
+                        let _ = &#base.#ident;
+                    };
+                }),
+            }
+        }
+        if entries.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "clone_fields! requires at least one field to clone after `=>`",
+            ));
+        }
+        // The source is written once, so a computed one is evaluated once
+        let exprs = syn::parse2(quote! { hoist; #(#entries),* })?;
+        Ok(CloneFields { exprs, checks })
+    }
+}
+
+/// Returns `true` if the field list contains `!ident`
+fn excludes(fields: &Punctuated<Field, Token![,]>, ident: &Ident) -> bool {
+    fields
+        .iter()
+        .any(|field| matches!(field, Field::Excluded(excluded) if excluded == ident))
+}
+
+impl ToTokens for CloneFields {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        tokens.extend(self.checks.clone());
+        self.exprs.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,49 @@
+use quote::ToTokens;
+use syn::Ident;
+use syn::parse::{Parse, ParseStream};
+
+use crate::CloneExprList;
+use crate::modifier::Modifier;
+
+/// Input of the `clone_guard!` macro: regular clone entries whose sources are
+/// locks, cloned through a `lock` guard unless the entry chooses `read`
+pub(crate) struct CloneGuard {
+    exprs: CloneExprList,
+}
+
+impl Parse for CloneGuard {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword = Ident::new("lock", input.span());
+        let mut exprs: CloneExprList = input.parse()?;
+        for expr in exprs.entries_mut() {
+            if expr.modifiers.iter().any(Modifier::acquires_guard) {
+                continue;
+            }
+            // The implied `lock` replaces the clone, so anything else that
+            // produces the value would be silently ignored.
+            if let Some(modifier) = expr.modifiers.iter().find(|m| m.replaces_clone()) {
+                return Err(syn::Error::new(
+                    modifier.keyword.span(),
+                    format!(
+                        "`{}` cannot be combined with the `lock` applied by `clone_guard!`: both replace the clone",
+                        modifier.keyword
+                    ),
+                ));
+            }
+            if let Some(method) = &expr.via {
+                return Err(syn::Error::new(
+                    method.span(),
+                    "`via` cannot be combined with the `lock` applied by `clone_guard!`",
+                ));
+            }
+            expr.modifiers.insert(0, Modifier::lock(keyword.clone()));
+        }
+        Ok(CloneGuard { exprs })
+    }
+}
+
+impl ToTokens for CloneGuard {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.exprs.to_tokens(tokens);
+    }
+}
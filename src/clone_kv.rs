@@ -0,0 +1,96 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Ident, Token, Type};
+
+/// Input of the `clone_kv!` macro.
+///
+/// - `clone_kv!(map => k, v)` clones two existing bindings (typically the
+///   loop variables of `for (k, v) in &map`).
+/// - `clone_kv!(entry as (k, v))` destructures a `(&K, &V)` tuple and clones
+///   both halves into new bindings.
+pub(crate) enum CloneKv {
+    Bindings {
+        key: Ident,
+        value: Ident,
+    },
+    Destructure {
+        source: Expr,
+        key: Ident,
+        value: Ident,
+    },
+}
+
+impl Parse for CloneKv {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let source: Expr = input.parse().map_err(|e| {
+            syn::Error::new(
+                e.span(),
+                "expected `<map> => key, value` or `<entry> as (key, value)`",
+            )
+        })?;
+
+        if input.peek(Token![=>]) {
+            let _arrow: Token![=>] = input.parse()?;
+            let key: Ident = input.parse()?;
+            let _comma: Token![,] = input.parse()?;
+            let value: Ident = input.parse()?;
+            let _trailing: Option<Token![,]> = input.parse()?;
+            return Ok(CloneKv::Bindings { key, value });
+        }
+
+        let Expr::Cast(cast) = source else {
+            return Err(syn::Error::new_spanned(
+                source,
+                "expected `<map> => key, value` or `<entry> as (key, value)`",
+            ));
+        };
+        let Type::Tuple(tuple) = &*cast.ty else {
+            return Err(syn::Error::new_spanned(
+                &cast.ty,
+                "expected a `(key, value)` pair of binding names after `as`",
+            ));
+        };
+        let names = tuple
+            .elems
+            .iter()
+            .map(type_as_ident)
+            .collect::<Option<Vec<_>>>();
+        match names.as_deref() {
+            Some([key, value]) => Ok(CloneKv::Destructure {
+                source: *cast.expr,
+                key: key.clone(),
+                value: value.clone(),
+            }),
+            _ => Err(syn::Error::new_spanned(
+                tuple,
+                "expected exactly two binding names: `(key, value)`",
+            )),
+        }
+    }
+}
+
+/// Returns the identifier of a type that is just a bare name, such as the `k`
+/// in `as (k, v)`.
+fn type_as_ident(ty: &Type) -> Option<Ident> {
+    match ty {
+        Type::Path(syn::TypePath { qself: None, path }) => path.get_ident().cloned(),
+        _ => None,
+    }
+}
+
+impl ToTokens for CloneKv {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        match self {
+            CloneKv::Bindings { key, value } => tokens.extend(quote! {
+                let #key = #key.clone();
+                let #value = #value.clone();
+            }),
+            CloneKv::Destructure { source, key, value } => tokens.extend(quote! {
+                let (#key, #value) = {
+                    let (#key, #value) = #source;
+                    (#key.clone(), #value.clone())
+                };
+            }),
+        }
+    }
+}
@@ -0,0 +1,61 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Token};
+
+use crate::receiver;
+
+/// Input of the `clone_push!` macro: `<collection>, <source>`
+pub(crate) struct ClonePush {
+    collection: Expr,
+    source: Expr,
+}
+
+impl Parse for ClonePush {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let collection = input.parse()?;
+        let _comma: Token![,] = input
+            .parse()
+            .map_err(|e| syn::Error::new(e.span(), "expected `<collection>, <source>`"))?;
+        let source = input.parse()?;
+        let _trailing: Option<Token![,]> = input.parse()?;
+        Ok(ClonePush { collection, source })
+    }
+}
+
+impl ToTokens for ClonePush {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let collection = receiver(&self.collection);
+        let value = receiver(&self.source);
+        tokens.extend(quote! { #collection.push(#value.clone()) });
+    }
+}
+
+/// Input of the `clone_insert!` macro: `<map>, <key>, <value>`
+pub(crate) struct CloneInsert {
+    map: Expr,
+    key: Expr,
+    value: Expr,
+}
+
+impl Parse for CloneInsert {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let expected =
+            |e: syn::Error| syn::Error::new(e.span(), "expected `<map>, <key>, <value>`");
+        let map = input.parse()?;
+        let _comma: Token![,] = input.parse().map_err(expected)?;
+        let key = input.parse()?;
+        let _comma: Token![,] = input.parse().map_err(expected)?;
+        let value = input.parse()?;
+        let _trailing: Option<Token![,]> = input.parse()?;
+        Ok(CloneInsert { map, key, value })
+    }
+}
+
+impl ToTokens for CloneInsert {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let map = receiver(&self.map);
+        let key = receiver(&self.key);
+        let value = receiver(&self.value);
+        tokens.extend(quote! { #map.insert(#key.clone(), #value.clone()) });
+    }
+}
@@ -0,0 +1,55 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Token};
+
+use crate::receiver;
+
+/// Input of the `clone_send!` macro: `[try] <channel>, <source>`.
+///
+/// Without `try` a failed send panics; with it the `Result` of `send` is
+/// returned so the caller can propagate it with `?`.
+pub(crate) struct CloneSend {
+    propagate: Option<Token![try]>,
+    channel: Expr,
+    source: Expr,
+}
+
+impl Parse for CloneSend {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let propagate = input.parse()?;
+        let channel = input.parse()?;
+        let _comma: Token![,] = input
+            .parse()
+            .map_err(|e| syn::Error::new(e.span(), "expected `<channel>, <source>`"))?;
+        let source = input.parse()?;
+        let _trailing: Option<Token![,]> = input.parse()?;
+        Ok(CloneSend {
+            propagate,
+            channel,
+            source,
+        })
+    }
+}
+
+impl ToTokens for CloneSend {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let CloneSend {
+            propagate,
+            channel,
+            source,
+        } = self;
+        let channel = receiver(channel);
+        let value = receiver(source);
+        let send = quote! { #channel.send(#value.clone()) };
+        tokens.extend(match propagate {
+            Some(_) => send,
+            None => quote! {
+                #send.expect(concat!(
+                    "failed to send `",
+                    stringify!(#source),
+                    "`: the receiving end has been dropped"
+                ))
+            },
+        });
+    }
+}
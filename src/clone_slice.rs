@@ -0,0 +1,40 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, Token};
+
+use crate::receiver;
+
+/// Input of the `clone_slice!` macro: `<destination>, <source>`
+pub(crate) struct CloneSlice {
+    destination: Expr,
+    source: Expr,
+}
+
+impl Parse for CloneSlice {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let destination = input.parse()?;
+        let _comma: Token![,] = input.parse().map_err(|e| {
+            syn::Error::new(
+                e.span(),
+                "expected `<destination>, <source>`, e.g. `clone_slice!(buffer, chunk)`",
+            )
+        })?;
+        let source = input.parse()?;
+        let _trailing: Option<Token![,]> = input.parse()?;
+        Ok(CloneSlice {
+            destination,
+            source,
+        })
+    }
+}
+
+impl ToTokens for CloneSlice {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let CloneSlice {
+            destination,
+            source,
+        } = self;
+        let destination = receiver(destination);
+        tokens.extend(quote! { #destination.clone_from_slice(&(#source)) });
+    }
+}
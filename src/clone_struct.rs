@@ -0,0 +1,86 @@
+use proc_macro2::Span;
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::{Expr, ExprStruct, Ident, Member, Token};
+
+use crate::kw;
+
+/// Input of the `clone_struct!` macro: `Foo { a, b } from src` builds a `Foo`
+/// whose listed fields are clones of the same fields of `src`.
+///
+/// The remaining fields come from a `..base` written inside the braces, or
+/// from `Default::default()` with a trailing `, ..default`.
+pub(crate) struct CloneStruct {
+    target: ExprStruct,
+    fields: Vec<Ident>,
+    source: Expr,
+}
+
+impl Parse for CloneStruct {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut target: ExprStruct = input.parse().map_err(|e| {
+            syn::Error::new(
+                e.span(),
+                "expected `Struct { field, field, .. } from <source>`",
+            )
+        })?;
+        let _from: kw::from = input.parse()?;
+        let source: Expr = input.parse()?;
+        let mut fields = Vec::new();
+        for field in &target.fields {
+            match &field.member {
+                Member::Named(ident) if field.colon_token.is_none() => fields.push(ident.clone()),
+                _ => {
+                    return Err(syn::Error::new_spanned(
+                        field,
+                        "clone_struct! expects field names only, e.g. `Foo { a, b } from src`",
+                    ));
+                }
+            }
+        }
+        if input.peek(Token![,]) && input.peek2(Token![..]) {
+            let _comma: Token![,] = input.parse()?;
+            let dots: Token![..] = input.parse()?;
+            let _default: kw::default = input.parse()?;
+            if let Some(rest) = &target.rest {
+                return Err(syn::Error::new_spanned(
+                    rest,
+                    "`..default` cannot be combined with `..base`: the remaining fields come from one or the other",
+                ));
+            }
+            target.dot2_token = Some(dots);
+            target.rest = Some(Box::new(syn::parse_quote! {
+                ::core::default::Default::default()
+            }));
+        }
+        let _trailing: Option<Token![,]> = input.parse()?;
+        Ok(CloneStruct {
+            target,
+            fields,
+            source,
+        })
+    }
+}
+
+impl ToTokens for CloneStruct {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let CloneStruct {
+            target,
+            fields,
+            source,
+        } = self;
+        let path = &target.path;
+        let rest = target.rest.as_ref().map(|rest| quote! { ..#rest });
+        // The source is borrowed once so a computed source is evaluated once
+        let base = Ident::new("__source", Span::mixed_site());
+        tokens.extend(quote! {
+            {
+                let #base = &#source;
+                #path {
+                    #(#fields: ::core::clone::Clone::clone(&#base.#fields),)*
+                    #rest
+                }
+            }
+        });
+    }
+}
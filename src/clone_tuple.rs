@@ -0,0 +1,64 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Ident, Token};
+
+use crate::{CloneExprList, receiver};
+
+/// A binding name of `clone_tuple!`, optionally mutable
+struct Name {
+    mutability: Option<Token![mut]>,
+    ident: Ident,
+}
+
+impl Parse for Name {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Name {
+            mutability: input.parse()?,
+            ident: input.parse()?,
+        })
+    }
+}
+
+/// Input of the `clone_tuple!` macro: `tuple => a, b, c` clones the
+/// positional fields `.0`, `.1`, `.2` into the named bindings, in order.
+///
+/// The entries are expanded like `clone!(tuple.0 as a, tuple.1 as b, ...)`,
+/// so a computed base is evaluated once.
+pub(crate) struct CloneTuple {
+    exprs: CloneExprList,
+}
+
+impl Parse for CloneTuple {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let source: Expr = input
+            .parse()
+            .map_err(|e| syn::Error::new(e.span(), "expected `<tuple> => name, name, ...`"))?;
+        let _arrow: Token![=>] = input.parse()?;
+        let names: Punctuated<Name, Token![,]> = Punctuated::parse_terminated(input)?;
+        if names.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "clone_tuple! requires at least one binding name after `=>`",
+            ));
+        }
+        let base = receiver(&source);
+        let entries = names.iter().enumerate().map(|(index, name)| {
+            let Name { mutability, ident } = name;
+            let index = syn::Index {
+                index: index as u32,
+                span: ident.span(),
+            };
+            quote! { #mutability #base.#index as #ident }
+        });
+        // The source is written once, so a computed one is evaluated once
+        let exprs = syn::parse2(quote! { hoist; #(#entries),* })?;
+        Ok(CloneTuple { exprs })
+    }
+}
+
+impl ToTokens for CloneTuple {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.exprs.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,40 @@
+use quote::{ToTokens, quote};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{Expr, Token};
+
+use crate::receiver;
+
+/// Input of the `cloned!` macro: comma-separated source expressions
+pub(crate) struct Cloned {
+    sources: Punctuated<Expr, Token![,]>,
+}
+
+impl Parse for Cloned {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let sources = Punctuated::parse_terminated(input)?;
+        if sources.is_empty() {
+            return Err(syn::Error::new(
+                input.span(),
+                "cloned! requires at least one expression",
+            ));
+        }
+        Ok(Cloned { sources })
+    }
+}
+
+impl ToTokens for Cloned {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let mut clones = self.sources.iter().map(|source| {
+            let source = receiver(source);
+            quote! { #source.clone() }
+        });
+        // A single entry is the clone itself, with no tuple, block or `;`
+        // around it, so it can be used as any argument
+        if self.sources.len() == 1 {
+            tokens.extend(clones.next());
+        } else {
+            tokens.extend(quote! { (#(#clones),*) });
+        }
+    }
+}
@@ -0,0 +1,335 @@
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{Expr, Ident, LitInt, LitStr, Path, Token};
+
+use crate::modifier::Modifier;
+use crate::{CloneExpr, binding_name, unwrap_group};
+
+/// Settings written before the entries and terminated by `;`, applying to
+/// every entry of the invocation, e.g. `clone!(mut prefix = "new_"; a, b)`
+#[derive(Default)]
+pub(crate) struct Directives {
+    /// `mut`: makes every binding mutable
+    mutability: Option<Token![mut]>,
+    /// `prefix = "..."`: prepends a string to every binding name
+    prefix: Option<LitStr>,
+    /// `suffix_index`: appends the zero-based position of the entry to every
+    /// binding name, `a, b` -> `a_0, b_1`
+    suffix_index: Option<Ident>,
+    /// `trace`: logs each clone with `tracing::trace!` (requires the
+    /// `tracing` feature)
+    trace: Option<Ident>,
+    /// `warn("...")`: reports a deprecation warning with the message at every
+    /// clone
+    warn: Option<LitStr>,
+    /// `count` / `count = COUNTER`: increments a `static AtomicUsize` before
+    /// every clone (requires the `metrics` feature)
+    count: Option<Path>,
+    /// `wrap = Type`: wraps every clone in `Type::new`
+    wrap: Option<(Ident, Path)>,
+    /// `using <path>`: calls a free function with a reference to every source
+    /// instead of its clone method
+    using: Option<Path>,
+    /// `intern = <path>`: calls an interner with a reference to every source,
+    /// like `using`
+    intern: Option<Path>,
+    /// `max_size = N`: asserts at compile time that every clone is at most
+    /// `N` bytes
+    max_size: Option<LitInt>,
+    /// `send`: asserts at compile time that every clone is `Send`
+    send: Option<Ident>,
+    /// `strict`: reports a redundant `.clone()` or `.to_owned()` on a source
+    /// as an error instead of a warning
+    strict: Option<Ident>,
+    /// `full_name`: names bindings of path sources after all their segments,
+    /// `config::database::URL` -> `config_database_URL`
+    full_name: Option<Ident>,
+    /// `must_use`: warns about every binding that is never used, even where
+    /// `unused_variables` is allowed
+    must_use: Option<Ident>,
+    /// `hoist`: evaluates a computed base read by several entries once
+    pub(crate) hoist: Option<Ident>,
+    /// Every directive written, in order, to detect one set by both a
+    /// cfg-gated block and the ungated block
+    written: Vec<(String, proc_macro2::Span)>,
+}
+
+impl Directives {
+    /// Returns `true` if the input contains a top-level `;`, which separates
+    /// the directives from the entries. Entries never contain one outside of
+    /// a delimited group, so `clone!(mut a)` is an entry and `clone!(mut; a)`
+    /// a directive.
+    pub(crate) fn peek(input: ParseStream) -> bool {
+        input
+            .step(|cursor| {
+                let mut rest = *cursor;
+                while let Some((tree, next)) = rest.token_tree() {
+                    if let proc_macro2::TokenTree::Punct(punct) = &tree
+                        && punct.as_char() == ';'
+                    {
+                        return Ok((true, *cursor));
+                    }
+                    rest = next;
+                }
+                Ok((false, *cursor))
+            })
+            .unwrap_or(false)
+    }
+
+    /// Rejects a directive written both in a cfg-gated block and in the
+    /// ungated block, since applying it twice would compound its effect,
+    /// e.g. `suffix_index` binding `a_0_0`
+    pub(crate) fn check_disjoint(&self, gated: &Directives) -> syn::Result<()> {
+        // `using` and `intern` set the same function
+        let slot = |name: &str| if name == "intern" { "using" } else { name }.to_owned();
+        for (name, span) in &self.written {
+            if gated
+                .written
+                .iter()
+                .any(|(other, _)| slot(other) == slot(name))
+            {
+                return Err(syn::Error::new(
+                    *span,
+                    format!(
+                        "`{}` is already set by the `#[cfg(...)]` directive block; write it in only one of the blocks",
+                        name
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+
+    /// Applies the directives to the parsed entries
+    pub(crate) fn apply(&self, exprs: &mut [CloneExpr]) -> syn::Result<()> {
+        for (index, expr) in exprs.iter_mut().enumerate() {
+            if expr.mutability.is_none() {
+                expr.mutability = self.mutability;
+            }
+            expr.trace |= self.trace.is_some();
+            if self.count.is_some() {
+                expr.counter.clone_from(&self.count);
+            }
+            if self.warn.is_some() {
+                expr.warning.clone_from(&self.warn);
+            }
+            if expr.max_size.is_none() {
+                expr.max_size.clone_from(&self.max_size);
+            }
+            expr.send |= self.send.is_some();
+            expr.strict |= self.strict.is_some();
+            if let Some(using) = self.using.as_ref().or(self.intern.as_ref()) {
+                expr.using = Some(using.clone());
+            }
+            if let Some((keyword, wrapper)) = &self.wrap {
+                expr.modifiers
+                    .push(Modifier::wrap(keyword.clone(), wrapper.clone()));
+            }
+            if self.full_name.is_some()
+                && expr.rename.is_none()
+                && let Expr::Path(path) = unwrap_group(&expr.inner)
+                && path.path.segments.len() > 1
+            {
+                let name = path
+                    .path
+                    .segments
+                    .iter()
+                    .map(|segment| segment.ident.unraw().to_string())
+                    .collect::<Vec<_>>()
+                    .join("_");
+                expr.rename = Some(Ident::new(&name, path.span()));
+                // Constants keep their upper-case segments in the joined name
+                expr.attrs.push(syn::parse_quote!(#[allow(non_snake_case)]));
+            }
+            if self.must_use.is_some() {
+                expr.attrs
+                    .push(syn::parse_quote!(#[warn(unused_variables)]));
+            }
+            if let Some(prefix) = &self.prefix {
+                let binding = expr.binding();
+                let name = format!("{}{}", prefix.value(), binding.unraw());
+                let ident = binding_name(&name, binding.span()).ok_or_else(|| {
+                    syn::Error::new(
+                        prefix.span(),
+                        format!("prefix produces an invalid binding name `{}`", name),
+                    )
+                })?;
+                expr.rename = Some(ident);
+            }
+            if let Some(suffix_index) = &self.suffix_index {
+                let binding = expr.binding();
+                if binding == "_" {
+                    return Err(syn::Error::new(
+                        suffix_index.span(),
+                        "`suffix_index` cannot suffix the placeholder name `_`",
+                    ));
+                }
+                let name = format!("{}_{}", binding.unraw(), index);
+                expr.rename = Some(Ident::new(&name, binding.span()));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Parse for Directives {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut directives = Directives::default();
+        loop {
+            if input.peek(Token![mut]) {
+                let mutability: Token![mut] = input.parse()?;
+                if directives.mutability.is_some() {
+                    return Err(duplicate(mutability.span, "mut"));
+                }
+                directives.mutability = Some(mutability);
+                directives
+                    .written
+                    .push((String::from("mut"), mutability.span));
+            } else {
+                let name = input.call(Ident::parse_any)?;
+                directives.written.push((name.to_string(), name.span()));
+                match name.to_string().as_str() {
+                    "prefix" => {
+                        let _eq: Token![=] = input.parse()?;
+                        if directives.prefix.is_some() {
+                            return Err(duplicate(name.span(), "prefix"));
+                        }
+                        directives.prefix = Some(input.parse()?);
+                    }
+                    "must_use" => {
+                        if directives.must_use.is_some() {
+                            return Err(duplicate(name.span(), "must_use"));
+                        }
+                        directives.must_use = Some(name);
+                    }
+                    "send" => {
+                        if directives.send.is_some() {
+                            return Err(duplicate(name.span(), "send"));
+                        }
+                        directives.send = Some(name);
+                    }
+                    "strict" => {
+                        if directives.strict.is_some() {
+                            return Err(duplicate(name.span(), "strict"));
+                        }
+                        directives.strict = Some(name);
+                    }
+                    "suffix_index" => {
+                        if directives.suffix_index.is_some() {
+                            return Err(duplicate(name.span(), "suffix_index"));
+                        }
+                        directives.suffix_index = Some(name);
+                    }
+                    "hoist" => {
+                        if directives.hoist.is_some() {
+                            return Err(duplicate(name.span(), "hoist"));
+                        }
+                        directives.hoist = Some(name);
+                    }
+                    "full_name" => {
+                        if directives.full_name.is_some() {
+                            return Err(duplicate(name.span(), "full_name"));
+                        }
+                        directives.full_name = Some(name);
+                    }
+                    "max_size" => {
+                        let _eq: Token![=] = input.parse()?;
+                        if directives.max_size.is_some() {
+                            return Err(duplicate(name.span(), "max_size"));
+                        }
+                        let max_size: LitInt = input.parse()?;
+                        max_size.base10_parse::<usize>()?;
+                        directives.max_size = Some(max_size);
+                    }
+                    "using" => {
+                        if directives.using.is_some() {
+                            return Err(duplicate(name.span(), "using"));
+                        }
+                        if directives.intern.is_some() {
+                            return Err(conflict(name.span()));
+                        }
+                        directives.using = Some(input.call(Path::parse_mod_style)?);
+                    }
+                    "intern" => {
+                        let _eq: Token![=] = input.parse()?;
+                        if directives.intern.is_some() {
+                            return Err(duplicate(name.span(), "intern"));
+                        }
+                        if directives.using.is_some() {
+                            return Err(conflict(name.span()));
+                        }
+                        directives.intern = Some(input.call(Path::parse_mod_style)?);
+                    }
+                    "wrap" => {
+                        let _eq: Token![=] = input.parse()?;
+                        if directives.wrap.is_some() {
+                            return Err(duplicate(name.span(), "wrap"));
+                        }
+                        directives.wrap = Some((name, input.call(Path::parse_mod_style)?));
+                    }
+                    "trace" => {
+                        if !cfg!(feature = "tracing") {
+                            return Err(syn::Error::new(
+                                name.span(),
+                                "the `trace` directive requires the `tracing` feature of letclone",
+                            ));
+                        }
+                        if directives.trace.is_some() {
+                            return Err(duplicate(name.span(), "trace"));
+                        }
+                        directives.trace = Some(name);
+                    }
+                    "warn" => {
+                        if directives.warn.is_some() {
+                            return Err(duplicate(name.span(), "warn"));
+                        }
+                        let content;
+                        syn::parenthesized!(content in input);
+                        directives.warn = Some(content.parse()?);
+                    }
+                    "count" => {
+                        if !cfg!(feature = "metrics") {
+                            return Err(syn::Error::new(
+                                name.span(),
+                                "the `count` directive requires the `metrics` feature of letclone",
+                            ));
+                        }
+                        if directives.count.is_some() {
+                            return Err(duplicate(name.span(), "count"));
+                        }
+                        directives.count = Some(if input.peek(Token![=]) {
+                            let _eq: Token![=] = input.parse()?;
+                            input.call(Path::parse_mod_style)?
+                        } else {
+                            Ident::new("LETCLONE_COUNTER", name.span()).into()
+                        });
+                    }
+                    _ => {
+                        return Err(syn::Error::new(
+                            name.span(),
+                            format!(
+                                "unknown clone! directive `{}` (entries are separated by `,`, directives end with `;`)",
+                                name
+                            ),
+                        ));
+                    }
+                }
+            }
+            if input.is_empty() || input.peek(Token![;]) {
+                return Ok(directives);
+            }
+        }
+    }
+}
+
+fn duplicate(span: proc_macro2::Span, name: &str) -> syn::Error {
+    syn::Error::new(span, format!("duplicate `{}` directive", name))
+}
+
+fn conflict(span: proc_macro2::Span) -> syn::Error {
+    syn::Error::new(
+        span,
+        "`using` and `intern` both name the function called instead of `clone`; write only one",
+    )
+}
@@ -0,0 +1,176 @@
+use proc_macro2::Span;
+use quote::{ToTokens, quote};
+use syn::{Attribute, Expr, Ident, Token};
+
+use crate::CloneExpr;
+use crate::modifier::Modifier;
+use crate::shadow::Shadowed;
+
+/// Consecutive entries sharing computed bases under the `hoist` directive,
+/// such as `record()` in `clone!(hoist; record().1 as name, record().3 as email)`,
+/// expanded as one `let`
+/// statement that evaluates each base once in a block and binds the clones
+/// from a tuple, so the bases and their temporaries are dropped at the end of
+/// that statement
+pub(crate) struct Hoisted {
+    /// Index of the first entry of the group
+    pub(crate) start: usize,
+    /// Index of the last entry of the group
+    pub(crate) end: usize,
+    /// Attributes shared by every entry of the group, put on the statement
+    attrs: Vec<Attribute>,
+    bases: Vec<(Ident, Expr)>,
+}
+
+impl Hoisted {
+    /// Emits the statement binding the entries of the group, with the
+    /// shadowing warnings of its entries inside the block
+    pub(crate) fn expand(
+        &self,
+        exprs: &[CloneExpr],
+        shadowed: &[Shadowed],
+        tokens: &mut proc_macro2::TokenStream,
+    ) {
+        let Hoisted {
+            start, end, attrs, ..
+        } = self;
+        let idents = self.bases.iter().map(|(ident, _)| ident);
+        let bases = self.bases.iter().map(|(_, base)| base);
+        let mut stmts = proc_macro2::TokenStream::new();
+        let mut patterns = Vec::new();
+        let mut values = Vec::new();
+        for (index, expr) in exprs.iter().enumerate().take(end + 1).skip(*start) {
+            for shadowed in shadowed.iter().filter(|s| s.index == index) {
+                shadowed.to_tokens(&mut stmts);
+            }
+            expr.expand(&mut stmts, true);
+            let binding = expr.binding();
+            // Modifiers such as `sorted` leave the binding mutable, as they do
+            // outside a group
+            let mutability = if expr.modifiers.iter().any(Modifier::implies_mut) {
+                Some(<Token![mut]>::default())
+            } else {
+                expr.mutability
+            };
+            patterns.push(quote! { #mutability #binding });
+            values.push(binding);
+        }
+        tokens.extend(quote! {
+            #(#attrs)*
+            let (#(#patterns),*) = {
+                #(let #idents = #bases;)*
+                #stmts
+                (#(#values),*)
+            };
+        });
+    }
+}
+
+/// Replaces computed bases that appear in more than one entry with hidden
+/// locals and returns the groups of entries that share them.
+///
+/// Places such as `record.1` are never hoisted, so entries reading plain
+/// variables and fields expand exactly as before. A group covers every entry
+/// between the first and the last use of its bases, which must all have the
+/// same attributes and bind owned values; otherwise each entry evaluates its
+/// base itself.
+pub(crate) fn hoist(exprs: &mut [CloneExpr]) -> Vec<Hoisted> {
+    let keys: Vec<Option<String>> = exprs
+        .iter_mut()
+        .map(|expr| {
+            let storage = binds_reference(expr);
+            computed_base(&mut expr.inner)
+                .filter(|_| !storage)
+                .map(|base| base.to_token_stream().to_string())
+        })
+        .collect();
+    // Range of entries covered by each base used more than once
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    let mut shared: Vec<&String> = Vec::new();
+    for (index, key) in keys.iter().enumerate() {
+        let Some(key) = key else { continue };
+        let Some(last) = keys.iter().rposition(|other| other.as_ref() == Some(key)) else {
+            continue;
+        };
+        let first = keys.iter().position(|other| other.as_ref() == Some(key));
+        if first != Some(index) || last == index {
+            continue;
+        }
+        let attrs = attrs_key(&exprs[index]);
+        let groupable = exprs[index..=last]
+            .iter()
+            .all(|expr| !binds_reference(expr) && attrs_key(expr) == attrs);
+        if groupable {
+            ranges.push((index, last));
+            shared.push(key);
+        }
+    }
+    // Overlapping ranges form a single statement
+    let mut groups: Vec<(usize, usize)> = Vec::new();
+    for (start, end) in ranges {
+        match groups.last_mut() {
+            Some(group) if start <= group.1 => group.1 = group.1.max(end),
+            _ => groups.push((start, end)),
+        }
+    }
+    let mut hoisted = Vec::new();
+    let mut names: Vec<(String, Ident)> = Vec::new();
+    for (start, end) in groups {
+        let mut bases = Vec::new();
+        for index in start..=end {
+            let Some(key) = &keys[index] else { continue };
+            if !shared.contains(&key) {
+                continue;
+            }
+            let base = computed_base(&mut exprs[index].inner).expect("base found above");
+            let ident = match names.iter().find(|(other, _)| other == key) {
+                Some((_, ident)) => ident.clone(),
+                None => {
+                    let ident = Ident::new(&format!("__base{}", names.len()), Span::mixed_site());
+                    names.push((key.clone(), ident.clone()));
+                    bases.push((ident.clone(), base.clone()));
+                    ident
+                }
+            };
+            *base = syn::parse_quote!(#ident);
+        }
+        hoisted.push(Hoisted {
+            start,
+            end,
+            attrs: exprs[start].attrs.clone(),
+            bases,
+        });
+    }
+    hoisted
+}
+
+/// Returns `true` if the entry binds a reference to a clone kept in hidden
+/// storage, which cannot be moved out of the group's block
+fn binds_reference(expr: &CloneExpr) -> bool {
+    expr.by_ref.is_some()
+        || expr
+            .modifiers
+            .iter()
+            .any(|modifier| modifier.binds_view() || modifier.binds_by_ref())
+}
+
+fn attrs_key(expr: &CloneExpr) -> String {
+    expr.attrs
+        .iter()
+        .map(|attr| attr.to_token_stream().to_string())
+        .collect()
+}
+
+/// Returns the computed expression at the root of a field access chain, e.g.
+/// `record()` in `record().inner.1`
+fn computed_base(expr: &mut Expr) -> Option<&mut Expr> {
+    match expr {
+        Expr::Group(group) => computed_base(&mut group.expr),
+        Expr::Field(field) => match &*field.base {
+            Expr::Field(_) => computed_base(&mut field.base),
+            Expr::Call(_) | Expr::MethodCall(_) | Expr::Macro(_) => Some(&mut field.base),
+            _ => None,
+        },
+        _ => None,
+    }
+}
@@ -18,9 +18,10 @@
 //! where `Group` is an implementation detail rather than a user-written Rust
 //! expression.
 //!
-//! This is different from a source-level parenthesized expression such as `(a)`
-//! or `(a + b)`, which parses as `syn::Expr::Paren` and is not treated as a
-//! supported clone target.
+//! A source-level parenthesized expression such as `(a.b)` parses as
+//! `syn::Expr::Paren`; its name is derived from the inner expression, so
+//! `clone!((a.b))` binds `b`. Parentheses around an expression without a
+//! derivable name, such as `(a + b)`, still need a rename.
 //!
 //! ## Examples
 //!
@@ -89,39 +90,759 @@
 //! };
 //! ```
 
-use quote::{ToTokens, quote};
+use proc_macro2::Span;
+use quote::{ToTokens, quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::parse::discouraged::Speculative;
 use syn::parse::{Parse, ParseStream};
 use syn::spanned::Spanned;
-use syn::{Expr, ExprGroup, Token};
+use syn::{Attribute, Expr, ExprGroup, Ident, LitStr, Token, Type};
 
-/// Represents a cloneable expression with optional `mut` modifier
+mod kw {
+    syn::custom_keyword!(via);
+    syn::custom_keyword!(context);
+    syn::custom_keyword!(or_else);
+    syn::custom_keyword!(from);
+    syn::custom_keyword!(default);
+}
+
+mod atomic_load;
+mod autoclone;
+mod box_clone;
+mod clone_arr;
+mod clone_assign;
+mod clone_captures;
+mod clone_fields;
+mod clone_guard;
+mod clone_kv;
+mod clone_push;
+mod clone_send;
+mod clone_slice;
+mod clone_struct;
+mod clone_tuple;
+mod cloned;
+mod directive;
+mod hoist;
+mod modifier;
+mod shadow;
+mod shared;
+mod spawn_clone;
+mod try_clone;
+mod try_clone_from;
+
+use directive::Directives;
+use modifier::Modifier;
+
+/// Represents a cloneable expression with optional `mut` modifier, value
+/// modifiers, an optional `as name` rename and an optional `: Type` annotation
 struct CloneExpr {
+    /// Outer attributes re-emitted on the generated `let`
+    attrs: Vec<Attribute>,
+    /// `ref mut`: binds a `&mut` to a clone kept in a hidden local
+    by_ref: Option<Token![ref]>,
     mutability: Option<Token![mut]>,
+    /// `unsafe deref`: wraps the clone of a raw pointer's pointee in an
+    /// `unsafe` block
+    unsafety: Option<Token![unsafe]>,
+    modifiers: Vec<Modifier>,
     inner: Expr,
+    rename: Option<Ident>,
+    ty: Option<Type>,
+    /// Method named by a `via <method>` clause, called instead of the default
+    /// clone method
+    via: Option<Ident>,
+    /// Trait of a `via <Trait>::method` clause, which calls the method with
+    /// fully qualified syntax to resolve ambiguities between traits
+    via_trait: Option<syn::Path>,
+    /// Set by the `using <path>` or `intern = <path>` directive: a free
+    /// function called with a reference to the source instead of the clone
+    /// method
+    using: Option<syn::Path>,
+    /// Method called on the source to produce the value, `clone` unless the
+    /// entry belongs to a macro such as `box_clone!`
+    clone_method: Ident,
+    /// Arguments passed to the clone method, empty unless the entry belongs
+    /// to a macro such as `atomic_load!`
+    clone_args: proc_macro2::TokenStream,
+    /// Predicate of a `where <closure>` clause, checked against the clone
+    /// with `debug_assert!`
+    predicate: Option<Expr>,
+    /// Set by the `trace` directive: logs the clone with `tracing::trace!`
+    trace: bool,
+    /// Set by the `warn("...")` directive: a message reported at the clone
+    /// as a deprecation warning
+    warning: Option<LitStr>,
+    /// Set by the `count` directive: a `static AtomicUsize` incremented
+    /// before the clone
+    counter: Option<syn::Path>,
+    /// Message of a `context "..."` clause, attached to the error of a
+    /// fallible clone with `anyhow::Context`
+    context: Option<LitStr>,
+    /// Block of an `or_else { ... }` clause, evaluated instead of propagating
+    /// the error of a fallible clone
+    or_else: Option<syn::Block>,
+    /// Set by the `max_size = N` directive: the largest size in bytes the
+    /// bound value may have, checked at compile time
+    max_size: Option<syn::LitInt>,
+    /// Set by the `send` directive: asserts at compile time that the bound
+    /// value is `Send`
+    send: bool,
+    /// Set by the `strict` directive: reports a redundant `.clone()` or
+    /// `.to_owned()` on the source as an error instead of a warning
+    strict: bool,
+    /// Set by `try_clone!`: the clone method returns a `Result` whose error
+    /// is propagated with `?`
+    fallible: bool,
 }
 
 impl Parse for CloneExpr {
     fn parse(input: ParseStream) -> syn::Result<Self> {
-        let mutability = if input.peek(Token![mut]) {
+        if input.peek(Token![#]) && input.peek2(Token![!]) {
+            return Err(syn::Error::new(
+                input.span(),
+                "inner attributes are not supported on clone expressions; use an outer attribute `#[...]`",
+            ));
+        }
+        let attrs = input.call(Attribute::parse_outer)?;
+        let by_ref: Option<Token![ref]> = input.parse()?;
+        let mutability: Option<Token![mut]> = input.parse()?;
+        if let (Some(by_ref), None) = (&by_ref, &mutability) {
+            return Err(syn::Error::new(
+                by_ref.span,
+                "expected `ref mut`: a shared reference to a fresh clone is rarely useful",
+            ));
+        }
+        let unsafety: Option<Token![unsafe]> =
+            if input.peek(Token![unsafe]) && !input.peek2(syn::token::Brace) {
+                Some(input.parse()?)
+            } else {
+                None
+            };
+        let mut modifiers: Vec<Modifier> = Vec::new();
+        while Modifier::peek(input) {
+            let modifier: Modifier = input.parse()?;
+            if modifier.replaces_clone()
+                && let Some(other) = modifiers.iter().find(|m| m.replaces_clone())
+            {
+                return Err(syn::Error::new(
+                    modifier.keyword.span(),
+                    format!(
+                        "`{}` cannot be combined with `{}`: both replace the clone",
+                        modifier.keyword, other.keyword
+                    ),
+                ));
+            }
+            if modifier.binds_view()
+                && let Some(by_ref) = &by_ref
+            {
+                return Err(syn::Error::new(
+                    by_ref.span,
+                    "`ref mut` cannot be combined with `view`, which binds a shared view of the clone",
+                ));
+            }
+            modifiers.push(modifier);
+        }
+        let (inner, mut rename) = match parse_string_rename(input)? {
+            Some((inner, rename)) => (inner, Some(rename)),
+            None => {
+                let inner: Expr = input.parse()
+                    .map_err(|e| syn::Error::new(e.span(), "expected a valid expression: field access (a.b), tuple index access (a.0), method call (a.method()), or path (var)"))?;
+                split_rename(inner)
+            }
+        };
+        for modifier in &modifiers {
+            modifier.validate(&inner)?;
+        }
+        let mut via = None;
+        let mut via_trait = None;
+        if rename.is_none() && input.peek(kw::via) {
+            let via_token: kw::via = input.parse()?;
+            if let Some(modifier) = modifiers.iter().find(|m| m.replaces_clone()) {
+                return Err(syn::Error::new(
+                    via_token.span,
+                    format!("`via` cannot be combined with `{}`", modifier.keyword),
+                ));
+            }
+            if input.peek(Token![<]) {
+                let _lt: Token![<] = input.parse()?;
+                let mut path: syn::Path = input.parse()?;
+                // The path is emitted in expression position, where generic
+                // arguments need a turbofish: `Convert::<i64>::snapshot`.
+                for segment in &mut path.segments {
+                    if let syn::PathArguments::AngleBracketed(args) = &mut segment.arguments {
+                        args.colon2_token = Some(Default::default());
+                    }
+                }
+                via_trait = Some(path);
+                let _gt: Token![>] = input.parse()?;
+                let _colons: Token![::] = input.parse()?;
+            }
+            via = Some(input.parse::<Ident>()?);
+            rename = parse_trailing_rename(input)?;
+        }
+        let mut context = None;
+        if rename.is_none() && input.peek(kw::context) && input.peek2(LitStr) {
+            let context_token: kw::context = input.parse()?;
+            if !cfg!(feature = "anyhow") {
+                return Err(syn::Error::new(
+                    context_token.span,
+                    "the `context` modifier requires the `anyhow` feature of letclone",
+                ));
+            }
+            context = Some(input.parse()?);
+            rename = parse_trailing_rename(input)?;
+        }
+        let mut or_else = None;
+        if input.peek(kw::or_else) && input.peek2(syn::token::Brace) {
+            let or_else_token: kw::or_else = input.parse()?;
+            if rename.is_some() || context.is_some() {
+                return Err(syn::Error::new(
+                    or_else_token.span,
+                    "`or_else` must directly follow the source and cannot be combined with `context`, e.g. `try_clone!(file or_else { return } as f)`",
+                ));
+            }
+            or_else = Some(input.parse()?);
+            rename = parse_trailing_rename(input)?;
+        }
+        if let Some(unsafety) = &unsafety {
+            if !modifiers.iter().any(|m| m.keyword == "deref") {
+                return Err(syn::Error::new(
+                    unsafety.span,
+                    "`unsafe` is only supported together with `deref`, e.g. `clone!(unsafe deref ptr as value)`",
+                ));
+            }
+            if rename.is_none() {
+                return Err(syn::Error::new(
+                    unsafety.span,
+                    "`unsafe deref` requires a rename, e.g. `clone!(unsafe deref ptr as value)`",
+                ));
+            }
+        }
+        if rename.is_none()
+            && let Some(modifier) = modifiers.iter().find(|m| m.requires_rename())
+        {
+            return Err(syn::Error::new(
+                modifier.keyword.span(),
+                format!(
+                    "`{}` requires a rename, e.g. `clone!({} nested as value)`",
+                    modifier.keyword, modifier.keyword
+                ),
+            ));
+        }
+        // Any expression can be cloned into a named binding; only deriving the
+        // name needs one of the supported forms.
+        if rename.is_none()
+            && let Err(e) = binding_ident(&inner)
+        {
+            return Err(syn::Error::new_spanned(&inner, e));
+        }
+        let binding = rename.clone().map_or_else(|| binding_ident(&inner), Ok);
+        if let Ok(binding) = binding
+            && PATH_KEYWORDS.contains(&binding.to_string().as_str())
+        {
+            return Err(syn::Error::new(
+                binding.span(),
+                format!(
+                    "`{}` cannot name a binding, even as a raw identifier; add another name, e.g. `clone!({} as this)`",
+                    binding,
+                    inner.to_token_stream(),
+                ),
+            ));
+        }
+        let ty = if input.peek(Token![:]) && !input.peek(Token![::]) {
+            let _colon: Token![:] = input.parse()?;
+            Some(input.parse()?)
+        } else {
+            None
+        };
+        if ty.is_none()
+            && let Some(modifier) = modifiers.iter().find(|m| m.requires_type())
+        {
+            return Err(syn::Error::new(
+                modifier.keyword.span(),
+                format!(
+                    "`{}` requires a target type annotation, e.g. `{} value as name: {}`",
+                    modifier.keyword,
+                    modifier.keyword,
+                    modifier.example_type()
+                ),
+            ));
+        }
+        let predicate = if input.peek(Token![where]) {
+            let _where: Token![where] = input.parse()?;
             Some(input.parse()?)
         } else {
             None
         };
-        let inner: Expr = input.parse()
-            .map_err(|e| syn::Error::new(e.span(), "expected a valid expression: field access (a.b), tuple index access (a.0), method call (a.method()), or path (var)"))?;
-        Ok(CloneExpr { mutability, inner })
+        Ok(CloneExpr {
+            attrs,
+            by_ref,
+            mutability,
+            unsafety,
+            modifiers,
+            inner,
+            rename,
+            ty,
+            via,
+            via_trait,
+            using: None,
+            clone_method: Ident::new("clone", proc_macro2::Span::call_site()),
+            clone_args: proc_macro2::TokenStream::new(),
+            predicate,
+            trace: false,
+            counter: None,
+            warning: None,
+            context,
+            or_else,
+            max_size: None,
+            send: false,
+            strict: false,
+            fallible: false,
+        })
+    }
+}
+
+impl CloneExpr {
+    /// Returns the source if it already ends with a `.clone()` or
+    /// `.to_owned()` call that the generated `clone` call would repeat
+    fn redundant_clone(&self) -> Option<&syn::ExprMethodCall> {
+        let adds_clone = self.via.is_none()
+            && self.using.is_none()
+            && self.clone_method == "clone"
+            && !self.modifiers.iter().any(Modifier::replaces_clone);
+        match unwrap_group(&self.inner) {
+            Expr::MethodCall(call)
+                if adds_clone
+                    && call.args.is_empty()
+                    && (call.method == "clone" || call.method == "to_owned") =>
+            {
+                Some(call)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the name of the generated binding: the rename if one was given,
+    /// otherwise the name derived from the source
+    fn binding(&self) -> Ident {
+        match &self.rename {
+            Some(rename) => rename.clone(),
+            None => binding_ident(unwrap_group(&self.inner)).expect("validated while parsing"),
+        }
+    }
+
+    /// Rejects an entry whose value would not come from the clone method set
+    /// by a macro such as `atomic_load!`: a modifier that replaces the clone,
+    /// a `via` clause or the `using` directive would silently skip it
+    pub(crate) fn check_clone_method(&self, macro_name: &str) -> syn::Result<()> {
+        let conflict = if let Some(modifier) = self.modifiers.iter().find(|m| m.replaces_clone()) {
+            Some((modifier.keyword.span(), modifier.keyword.to_string()))
+        } else if let Some(method) = &self.via {
+            Some((method.span(), String::from("via")))
+        } else {
+            self.using
+                .as_ref()
+                .map(|using| (using.span(), String::from("using")))
+        };
+        match conflict {
+            Some((span, name)) => Err(syn::Error::new(
+                span,
+                format!(
+                    "`{}` cannot be used in `{}!`, which calls `{}` on the source",
+                    name, macro_name, self.clone_method
+                ),
+            )),
+            None => Ok(()),
+        }
     }
 }
 
 impl ToTokens for CloneExpr {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        tokens.extend(quote! { let });
-        if let Some(m) = &self.mutability {
-            tokens.extend(quote! { #m });
+        self.expand(tokens, false);
+    }
+}
+
+impl CloneExpr {
+    /// Emits the statements of the entry. A `grouped` entry is bound inside
+    /// the block of a hoisted group and moved out of it, so its `mut` is
+    /// left to the group's pattern.
+    fn expand(&self, tokens: &mut proc_macro2::TokenStream, grouped: bool) {
+        let inner = unwrap_group(&self.inner);
+        let ident = self.binding();
+        let receiver = self
+            .modifiers
+            .iter()
+            .fold(receiver(inner), |receiver, modifier| {
+                modifier.receiver(receiver)
+            });
+        let mut value = self
+            .modifiers
+            .iter()
+            .find_map(|modifier| modifier.source(&receiver))
+            .unwrap_or_else(|| match (&self.via, &self.via_trait) {
+                (Some(via), Some(via_trait)) => quote! { #via_trait::#via(&#receiver) },
+                (Some(via), None) => quote! { #receiver.#via() },
+                (None, _) if let Some(using) = &self.using => {
+                    // Located at the source so a type mismatch with the
+                    // function's parameter points at the entry
+                    let argument = quote_spanned! {inner.span()=> &#receiver};
+                    quote! { #using(#argument) }
+                }
+                // Literals and `vec!`/`format!` are already fresh values, so
+                // they are bound as is
+                (None, _) if is_fresh_value(inner) && self.clone_method == "clone" => {
+                    receiver.clone()
+                }
+                (None, _) => {
+                    // Located at the source so errors such as a missing
+                    // `Clone` impl point at the entry, not the whole macro
+                    let mut method = self.clone_method.clone();
+                    method.set_span(method.span().located_at(inner.span()));
+                    let args = &self.clone_args;
+                    quote! { #receiver.#method(#args) }
+                }
+            });
+        // The block takes the span of the user's `unsafe`, so the
+        // `unsafe_code` lint and errors point at it
+        if let Some(unsafety) = &self.unsafety {
+            value = quote_spanned! {unsafety.span=> #unsafety { #value } };
         }
-        let inner = &self.inner;
-        extend(inner, tokens);
+        if let Some(context) = &self.context {
+            value = if self.fallible {
+                quote! { ::anyhow::Context::context(#value, #context) }
+            } else {
+                quote_spanned! {context.span()=>
+                    ::core::compile_error!("`context` is only supported by `try_clone!`")
+                }
+            };
+        }
+        if let Some(or_else) = &self.or_else {
+            value = if self.fallible {
+                let cloned = Ident::new("__value", Span::mixed_site());
+                quote! {
+                    match #value {
+                        ::core::result::Result::Ok(#cloned) => #cloned,
+                        ::core::result::Result::Err(_) => #or_else,
+                    }
+                }
+            } else {
+                quote_spanned! {or_else.span()=>
+                    ::core::compile_error!("`or_else` is only supported by `try_clone!`")
+                }
+            };
+        } else if self.fallible {
+            value = quote! { #value? };
+        }
+        for modifier in &self.modifiers {
+            value = modifier.apply(value, self.ty.as_ref());
+        }
+        let attrs = &self.attrs;
+        if self.trace {
+            tokens.extend(quote! {
+                #(#attrs)*
+                ::tracing::trace!("cloning {}", stringify!(#inner));
+            });
+        }
+        if let Some(warning) = &self.warning {
+            // Located at the source so the warning points at the entry
+            let warning = deprecation_warning("clone_warning", warning, self.inner.span());
+            tokens.extend(quote! { #(#attrs)* #warning });
+        }
+        if let Some(call) = self.redundant_clone() {
+            let message = format!(
+                "`{}` is cloned again by clone!; remove the `.{}()` call",
+                call.to_token_stream(),
+                call.method,
+            );
+            let report = if self.strict {
+                quote_spanned! {call.method.span()=> ::core::compile_error!(#message);}
+            } else {
+                let message = LitStr::new(&message, call.method.span());
+                deprecation_warning("redundant_clone", &message, call.method.span())
+            };
+            tokens.extend(quote! { #(#attrs)* #report });
+        }
+        if let Some(counter) = &self.counter {
+            tokens.extend(quote! {
+                #(#attrs)*
+                #counter.fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);
+            });
+        }
+        let mutability = if self.modifiers.iter().any(Modifier::implies_mut) {
+            Some(<Token![mut]>::default())
+        } else if grouped {
+            None
+        } else {
+            self.mutability
+        };
+        let ty = self.ty.as_ref().map(|ty| quote! { : #ty });
+        let post = |binding: &Ident| {
+            let stmts = self
+                .modifiers
+                .iter()
+                .filter_map(|m| m.post(binding, &self.inner));
+            stmts
+                .map(|stmt| quote! { #(#attrs)* #stmt })
+                .collect::<proc_macro2::TokenStream>()
+        };
+        let check = |value: &Ident| {
+            let predicate = self.predicate.as_ref()?;
+            let attrs = &self.attrs;
+            // Passing the predicate to a function bounded by `FnOnce(&T)` lets
+            // closure parameters be inferred without annotations.
+            let check = Ident::new("__check", Span::mixed_site());
+            Some(quote! {
+                #(#attrs)*
+                debug_assert!(
+                    {
+                        fn #check<T: ?Sized>(value: &T, predicate: impl ::core::ops::FnOnce(&T) -> bool) -> bool {
+                            predicate(value)
+                        }
+                        #check(&#value, #predicate)
+                    },
+                    // A fixed format string, since the predicate may contain braces
+                    "clone! post-condition failed for `{}`: {}",
+                    stringify!(#ident),
+                    stringify!(#predicate),
+                );
+            })
+        };
+        // The type of the binding is only known to the compiler, so the size is
+        // checked by a generic function instantiated with it.
+        let size_check = |value: &Ident| {
+            let max_size = self.max_size.as_ref()?;
+            let assert_size = Ident::new("__assert_size", Span::mixed_site());
+            Some(quote! {
+                #(#attrs)*
+                {
+                    fn #assert_size<T>(_: &T) {
+                        const {
+                            assert!(
+                                ::core::mem::size_of::<T>() <= #max_size,
+                                concat!("clone! `", stringify!(#ident), "` is larger than max_size = ", #max_size, " bytes"),
+                            )
+                        }
+                    }
+                    #assert_size(&#value);
+                }
+            })
+        };
+        let send_check = |value: &Ident| {
+            if !self.send {
+                return None;
+            }
+            let assert_send = Ident::new("__assert_send", Span::mixed_site());
+            // Spanned at the source so the error points at the offending entry
+            let call = quote_spanned! {self.inner.span()=> #assert_send(&#value); };
+            Some(quote! {
+                #(#attrs)*
+                {
+                    fn #assert_send<T: ::core::marker::Send>(_: &T) {}
+                    #call
+                }
+            })
+        };
+        let view = self.modifiers.iter().any(Modifier::binds_view);
+        if self.by_ref.is_some() || view {
+            // `&mut <temporary>` would not live past the statement, so the
+            // clone is stored in a local that user code cannot name.
+            let storage = Ident::new(
+                &format!("__{}", ident.unraw()),
+                Span::mixed_site().located_at(ident.span()),
+            );
+            let post = post(&storage);
+            let check = check(&storage);
+            let size_check = size_check(&storage);
+            let send_check = send_check(&storage);
+            let (storage_mut, binding) = if view {
+                // Modifiers such as `reserve` mutate the clone, not the view
+                let storage_mut = self
+                    .modifiers
+                    .iter()
+                    .any(Modifier::implies_mut)
+                    .then(<Token![mut]>::default);
+                let mutability = self.mutability;
+                (storage_mut, quote! { let #mutability #ident = &*#storage; })
+            } else {
+                (
+                    Some(<Token![mut]>::default()),
+                    quote! { let #ident = &mut #storage; },
+                )
+            };
+            tokens.extend(quote! {
+                #(#attrs)*
+                let #storage_mut #storage #ty = #value;
+                #size_check
+                #send_check
+                #post
+                #check
+                #(#attrs)*
+                #binding
+            });
+            return;
+        }
+        let post = post(&ident);
+        let check = check(&ident);
+        let size_check = size_check(&ident);
+        let send_check = send_check(&ident);
+        // `let ref x = <temporary>;` extends the temporary to the end of the
+        // enclosing block, so no separate storage is needed.
+        let by_ref = self
+            .modifiers
+            .iter()
+            .any(Modifier::binds_by_ref)
+            .then(<Token![ref]>::default);
+        tokens.extend(quote! {
+            #(#attrs)*
+            let #by_ref #mutability #ident #ty = #value;
+            #size_check
+            #send_check
+            #post
+            #check
+        });
+    }
+}
+
+/// Splits a trailing `as name` rename off a parsed expression.
+///
+/// `syn` parses `a.b as name` as a cast to the type `name`. Because `as` binds
+/// tighter than binary and range operators, `a + b as name` parses as
+/// `a + (b as name)`, so the rename is looked for along the right-hand spine
+/// of the expression and removed from there.
+fn split_rename(expr: Expr) -> (Expr, Option<Ident>) {
+    match expr {
+        Expr::Cast(cast) => match rename_ident(&cast.ty) {
+            Some(ident) => (*cast.expr, Some(ident)),
+            None => (Expr::Cast(cast), None),
+        },
+        Expr::Binary(mut binary) => {
+            let (right, rename) = split_rename(*binary.right);
+            binary.right = Box::new(right);
+            (Expr::Binary(binary), rename)
+        }
+        Expr::Assign(mut assign) => {
+            let (right, rename) = split_rename(*assign.right);
+            assign.right = Box::new(right);
+            (Expr::Assign(assign), rename)
+        }
+        Expr::Range(mut range) => match range.end.take() {
+            Some(end) => {
+                let (end, rename) = split_rename(*end);
+                range.end = Some(Box::new(end));
+                (Expr::Range(range), rename)
+            }
+            None => (Expr::Range(range), None),
+        },
+        expr => (expr, None),
+    }
+}
+
+/// Parses a source followed by a string rename, `source as "name"`.
+///
+/// `syn` would reject the string as the target type of a cast, so the tokens
+/// before the rename are collected and parsed on their own. Returns `None`
+/// without consuming anything if the entry has no string rename.
+fn parse_string_rename(input: ParseStream) -> syn::Result<Option<(Expr, Ident)>> {
+    let fork = input.fork();
+    let mut tokens = proc_macro2::TokenStream::new();
+    while !(fork.peek(Token![as]) && fork.peek2(LitStr)) {
+        if fork.is_empty()
+            || fork.peek(Token![,])
+            || fork.peek(kw::via)
+            || (fork.peek(kw::context) && fork.peek2(LitStr))
+            || (fork.peek(kw::or_else) && fork.peek2(syn::token::Brace))
+        {
+            return Ok(None);
+        }
+        tokens.extend([fork.parse::<proc_macro2::TokenTree>()?]);
     }
+    input.advance_to(&fork);
+    let _as: Token![as] = input.parse()?;
+    let rename = string_ident(&input.parse()?)?;
+    let inner = syn::parse2(tokens)?;
+    Ok(Some((inner, rename)))
+}
+
+/// Parses an optional `as name` or `as "name"` after a clause that ends the
+/// source, such as `via method`
+fn parse_trailing_rename(input: ParseStream) -> syn::Result<Option<Ident>> {
+    if !input.peek(Token![as]) {
+        return Ok(None);
+    }
+    let _as: Token![as] = input.parse()?;
+    if input.peek(LitStr) {
+        string_ident(&input.parse()?).map(Some)
+    } else {
+        input.parse().map(Some)
+    }
+}
+
+/// Converts the string of a `as "name"` rename into an identifier
+fn string_ident(lit: &LitStr) -> syn::Result<Ident> {
+    binding_name(&lit.value(), lit.span()).ok_or_else(|| {
+        syn::Error::new(
+            lit.span(),
+            format!(
+                "{:?} is not a valid binding name; expected an identifier such as \"name\"",
+                lit.value()
+            ),
+        )
+    })
+}
+
+/// Names of the primitive types, which are casts rather than renames after
+/// `as`
+const PRIMITIVE_TYPES: &[&str] = &[
+    "bool", "char", "str", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16",
+    "u32", "u64", "u128", "usize",
+];
+
+/// Returns the identifier when the target of an `as` is a bare name.
+///
+/// A primitive type name such as `u64` is a cast target, not a rename, so
+/// `x as u64` stays a cast expression.
+fn rename_ident(ty: &syn::Type) -> Option<Ident> {
+    match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path
+            .get_ident()
+            .filter(|ident| !PRIMITIVE_TYPES.contains(&ident.to_string().as_str()))
+            .cloned(),
+        _ => None,
+    }
+}
+
+/// Keywords that are valid in paths but cannot be bound by `let`, not even
+/// as raw identifiers
+const PATH_KEYWORDS: &[&str] = &["self", "Self", "super", "crate"];
+
+/// Creates the identifier of a generated binding name, written as a raw
+/// identifier if the name is a keyword such as `type`. Returns `None` if the
+/// name is not a valid binding name.
+fn binding_name(name: &str, span: Span) -> Option<Ident> {
+    let mut ident = syn::parse_str::<Ident>(name)
+        .or_else(|_| syn::parse_str::<Ident>(&format!("r#{}", name)))
+        .ok()?;
+    ident.set_span(span);
+    Some(ident)
+}
+
+/// Looks through the invisible groups produced by macro expansion
+fn unwrap_group(expr: &Expr) -> &Expr {
+    match expr {
+        Expr::Group(ExprGroup { expr, .. }) => unwrap_group(expr),
+        expr => expr,
+    }
+}
+
+/// Prefixes a description with the matching indefinite article
+fn with_article(description: &str) -> String {
+    let article = if description.starts_with(['a', 'e', 'i', 'o', 'u']) {
+        "an"
+    } else {
+        "a"
+    };
+    format!("{} {}", article, description)
 }
 
 fn expr_variant_description(expr: &Expr) -> &'static str {
@@ -170,48 +891,127 @@ fn expr_variant_description(expr: &Expr) -> &'static str {
     }
 }
 
-fn extend(expr: &Expr, tokens: &mut proc_macro2::TokenStream) {
+/// Parenthesizes an expression if needed so a method can be called on it
+pub(crate) fn receiver(expr: &Expr) -> proc_macro2::TokenStream {
+    if needs_parens(expr) {
+        quote! { (#expr) }
+    } else {
+        expr.to_token_stream()
+    }
+}
+
+/// Returns `true` if the expression must be parenthesized before a method
+/// call can be appended to it
+fn needs_parens(expr: &Expr) -> bool {
+    matches!(
+        expr,
+        Expr::Assign(_)
+            | Expr::Binary(_)
+            | Expr::Cast(_)
+            | Expr::Closure(_)
+            | Expr::Let(_)
+            | Expr::Range(_)
+            | Expr::RawAddr(_)
+            | Expr::Reference(_)
+            | Expr::Return(_)
+            | Expr::Break(_)
+            | Expr::Unary(_)
+            | Expr::Yield(_)
+    )
+}
+
+/// Returns `true` if the source evaluates to a new owned value that would
+/// only be copied by cloning it: a literal, or a `vec!` or `format!`
+/// invocation
+fn is_fresh_value(expr: &Expr) -> bool {
+    match expr {
+        Expr::Lit(_) => true,
+        Expr::Macro(syn::ExprMacro { mac, .. }) => mac
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "vec" || segment.ident == "format"),
+        _ => false,
+    }
+}
+
+/// Derives the binding name from a supported source expression
+fn binding_ident(expr: &Expr) -> Result<Ident, String> {
     match expr {
         Expr::Field(syn::ExprField {
-            base,
             member: syn::Member::Named(field_name),
             ..
-        }) => {
-            tokens.extend(quote! {
-                #field_name = #base.#field_name.clone();
-            });
-        }
+        }) => Ok(field_name.clone()),
         Expr::Field(syn::ExprField {
-            base,
             member: syn::Member::Unnamed(index),
             ..
-        }) => {
-            let index_num = index.index;
-            let ident = syn::Ident::new(&format!("field_{}", index_num), index.span());
-            tokens.extend(quote! {
-                #ident = #base.#index.clone();
-            });
+        }) => Ok(Ident::new(&format!("field_{}", index.index), index.span())),
+        Expr::MethodCall(expr_method_call) => Ok(expr_method_call.method.clone()),
+        Expr::Call(syn::ExprCall { func, .. }) if matches!(&**func, Expr::Path(_)) => {
+            binding_ident(func)
         }
-        Expr::MethodCall(expr_method_call) => {
-            let method = &expr_method_call.method;
-            tokens.extend(quote! {
-                #method = #expr.clone();
-            });
+        Expr::Path(syn::ExprPath { path, .. }) => Ok(path.segments.last().unwrap().ident.clone()),
+        Expr::Group(ExprGroup { expr, .. }) | Expr::Paren(syn::ExprParen { expr, .. }) => {
+            binding_ident(expr)
         }
-        Expr::Path(syn::ExprPath { path, .. }) => {
-            let ident = &path.segments.last().unwrap().ident;
-            tokens.extend(quote! {
-                #ident = #expr.clone();
-            });
+        Expr::Try(syn::ExprTry { expr, .. }) | Expr::Await(syn::ExprAwait { base: expr, .. }) => {
+            binding_ident(expr)
         }
-        Expr::Group(ExprGroup { expr, .. }) => {
-            extend(&expr, tokens);
+        Expr::Cast(cast) => Err(format!(
+            "`as {}` is a cast, not a rename: a rename is a single name that is not a primitive type. Add a rename to clone the cast value, e.g. `clone!({} as name)`",
+            cast.ty.to_token_stream(),
+            cast.to_token_stream(),
+        )),
+        Expr::Lit(lit) => Err(format!(
+            "cannot derive a binding name from a literal; use `clone!({} as name)`",
+            lit.to_token_stream(),
+        )),
+        Expr::Macro(syn::ExprMacro { mac, .. }) => {
+            let args = match mac.delimiter {
+                syn::MacroDelimiter::Paren(_) => "(..)",
+                syn::MacroDelimiter::Brace(_) => "{..}",
+                syn::MacroDelimiter::Bracket(_) => "[..]",
+            };
+            Err(format!(
+                "cannot derive a binding name from a macro invocation; use `clone!({}!{} as name)`",
+                mac.path.to_token_stream(),
+                args,
+            ))
         }
-        _ => {
-            panic!(
-                "clone! macro does not support {}. Supported forms: field access (`a.b`), tuple index access (`a.0`), method call (`a.method()`), or path (`var`).",
-                expr_variant_description(expr)
-            );
+        Expr::Assign(syn::ExprAssign { left, right, .. }) => {
+            // `b = a.clone()` suggests `a as b`, not `a.clone() as b`
+            let source = match &**right {
+                Expr::MethodCall(call) if call.method == "clone" && call.args.is_empty() => {
+                    &call.receiver
+                }
+                source => source,
+            };
+            Err(format!(
+                "entries are sources, not assignments; write `clone!({} as {})` to bind the clone under another name",
+                source.to_token_stream(),
+                left.to_token_stream(),
+            ))
+        }
+        _ => Err(format!(
+            "cannot derive a binding name from {}; add a rename such as `clone!(<expr> as name)`. Names are derived from field access (`a.b`), tuple index access (`a.0`), method call (`a.method()`), function call (`f()`), or path (`var`).",
+            with_article(expr_variant_description(expr))
+        )),
+    }
+}
+
+/// Returns a block that reports `message` as a deprecation warning at `span`.
+///
+/// Calling a `#[deprecated]` function is the only way to emit a custom warning
+/// from stable proc-macro output; the warning reads "use of deprecated
+/// function `..::<name>`: <message>".
+fn deprecation_warning(name: &str, message: &LitStr, span: Span) -> proc_macro2::TokenStream {
+    let function = Ident::new(name, Span::mixed_site());
+    let call = Ident::new(name, Span::mixed_site().located_at(span));
+    quote! {
+        {
+            #[deprecated = #message]
+            fn #function() {}
+            #call();
         }
     }
 }
@@ -219,21 +1019,113 @@ fn extend(expr: &Expr, tokens: &mut proc_macro2::TokenStream) {
 /// Represents a list of clone expressions
 struct CloneExprList {
     exprs: Vec<CloneExpr>,
+    /// Computed bases shared by several entries under the `hoist` directive,
+    /// evaluated once
+    hoisted: Vec<hoist::Hoisted>,
+    /// Sources reading a binding introduced by an earlier entry
+    shadowed: Vec<shadow::Shadowed>,
+    /// Closure or async block written after `=>`, made `move` so it captures
+    /// the clones
+    body: Option<Expr>,
+    /// The variant expanded without the gated directives, whose statements
+    /// are emitted after those of this list
+    otherwise: Option<Box<CloneExprList>>,
 }
 
 impl Parse for CloneExprList {
     fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut attrs = Vec::new();
+        // `#[...] =>` applies to the whole invocation; attributes without the
+        // arrow belong to the first entry.
+        let fork = input.fork();
+        if fork
+            .call(Attribute::parse_outer)
+            .is_ok_and(|a| !a.is_empty())
+            && fork.peek(Token![=>])
+        {
+            attrs = input.call(Attribute::parse_outer)?;
+            let _arrow: Token![=>] = input.parse()?;
+        }
+        // `#[cfg(...)] directives;` applies the directives only under the
+        // predicate. Attributes before a directive block cannot belong to an
+        // entry, since the entries come after it.
+        let mut gated = None;
+        let fork = input.fork();
+        if fork
+            .call(Attribute::parse_outer)
+            .is_ok_and(|a| !a.is_empty())
+            && Directives::peek(&fork)
+        {
+            let predicate = cfg_predicate(&input.call(Attribute::parse_outer)?)?;
+            let directives: Directives = input.parse()?;
+            let _semi: Token![;] = input.parse()?;
+            gated = Some((predicate, directives));
+        }
+        let mut directives = Directives::default();
+        if Directives::peek(input) {
+            directives = input.parse()?;
+            let _semi: Token![;] = input.parse()?;
+        }
+        let Some((predicate, gated)) = gated else {
+            return CloneExprList::parse_entries(input, &attrs, None, &[&directives]);
+        };
+        directives.check_disjoint(&gated)?;
+        // The predicate is only known to the compiler, so both variants are
+        // expanded and each statement is gated with `cfg` or `cfg(not)`
+        let entries: proc_macro2::TokenStream = input.parse()?;
+        let enabled: Attribute = syn::parse_quote!(#[cfg(#predicate)]);
+        let disabled: Attribute = syn::parse_quote!(#[cfg(not(#predicate))]);
+        let mut list = syn::parse::Parser::parse2(
+            |input: ParseStream| {
+                CloneExprList::parse_entries(input, &attrs, Some(enabled), &[&gated, &directives])
+            },
+            entries.clone(),
+        )?;
+        let mut otherwise = syn::parse::Parser::parse2(
+            |input: ParseStream| {
+                CloneExprList::parse_entries(input, &attrs, Some(disabled), &[&directives])
+            },
+            entries,
+        )?;
+        otherwise.body = None;
+        list.otherwise = Some(Box::new(otherwise));
+        Ok(list)
+    }
+}
+
+impl CloneExprList {
+    /// Parses the entries following the directives, applying the attributes
+    /// written as `#[...] =>`, the gate and the directives to each of them
+    fn parse_entries(
+        input: ParseStream,
+        attrs: &[Attribute],
+        gate: Option<Attribute>,
+        directives: &[&Directives],
+    ) -> syn::Result<Self> {
         let mut exprs = Vec::new();
+        let mut body = None;
         while !input.is_empty() {
             let expr: CloneExpr = input.parse().map_err(|e| {
                 syn::Error::new(e.span(), format!("failed to parse clone expression: {}", e))
             })?;
             exprs.push(expr);
-            if input.peek(Token![,]) {
-                let _comma: Token![,] = input.parse()?;
-            } else {
+            if input.peek(Token![=>]) {
+                let _arrow: Token![=>] = input.parse()?;
+                body = Some(parse_capture_body(input)?);
+                break;
+            }
+            // Each entry must be followed by a comma or the end of the input;
+            // anything else would otherwise be silently dropped.
+            if input.is_empty() {
                 break;
             }
+            if !input.peek(Token![,]) {
+                return Err(syn::Error::new(
+                    input.span(),
+                    "expected `,` between clone expressions",
+                ));
+            }
+            let _comma: Token![,] = input.parse()?;
         }
         if exprs.is_empty() {
             return Err(syn::Error::new(
@@ -241,14 +1133,114 @@ impl Parse for CloneExprList {
                 "clone! macro requires at least one expression",
             ));
         }
-        Ok(CloneExprList { exprs })
+        // Attributes written as `#[...] =>` apply to every generated `let`
+        for expr in &mut exprs {
+            expr.attrs.splice(0..0, attrs.iter().chain(&gate).cloned());
+        }
+        for directives in directives {
+            directives.apply(&mut exprs)?;
+        }
+        let shadowed = shadow::shadowed(&exprs);
+        // Equal calls may return different values, so a base is only shared
+        // when asked for
+        let hoisted = if directives.iter().any(|d| d.hoist.is_some()) {
+            hoist::hoist(&mut exprs)
+        } else {
+            Vec::new()
+        };
+        Ok(CloneExprList {
+            exprs,
+            hoisted,
+            shadowed,
+            body,
+            otherwise: None,
+        })
+    }
+
+    /// Returns every entry, including those of the variant expanded without
+    /// a cfg-gated directive block
+    fn entries_mut(&mut self) -> impl Iterator<Item = &mut CloneExpr> {
+        let otherwise = self.otherwise.as_deref_mut().map(|list| &mut list.exprs);
+        self.exprs.iter_mut().chain(otherwise.into_iter().flatten())
     }
 }
 
+/// Returns the predicate of the `#[cfg(...)]` attributes gating a directive
+/// block, combined with `all(...)` when there are several
+fn cfg_predicate(attrs: &[Attribute]) -> syn::Result<proc_macro2::TokenStream> {
+    let predicates = attrs
+        .iter()
+        .map(|attr| match &attr.meta {
+            syn::Meta::List(list) if list.path.is_ident("cfg") => Ok(list.tokens.clone()),
+            _ => Err(syn::Error::new_spanned(
+                attr,
+                "only `#[cfg(...)]` can gate a directive block, e.g. `clone!(#[cfg(feature = \"sync\")] wrap = Arc; state)`",
+            )),
+        })
+        .collect::<syn::Result<Vec<_>>>()?;
+    Ok(match predicates.as_slice() {
+        [predicate] => predicate.clone(),
+        predicates => quote! { all(#(#predicates),*) },
+    })
+}
+
+/// Parses the closure or async block following `=>` and makes it `move`
+fn parse_capture_body(input: ParseStream) -> syn::Result<Expr> {
+    let mut body: Expr = input.parse()?;
+    match &mut body {
+        Expr::Closure(closure) if closure.capture.is_none() => {
+            closure.capture = Some(Default::default());
+        }
+        Expr::Async(block) if block.capture.is_none() => {
+            block.capture = Some(Default::default());
+        }
+        Expr::Closure(_) | Expr::Async(_) => {}
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &body,
+                "expected a closure or an async block after `=>`, e.g. `clone!(state => async move { ... })`",
+            ));
+        }
+    }
+    if !input.is_empty() {
+        return Err(syn::Error::new(
+            input.span(),
+            "the closure or async block after `=>` must come last",
+        ));
+    }
+    Ok(body)
+}
+
 impl ToTokens for CloneExprList {
     fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
-        for expr in &self.exprs {
-            expr.to_tokens(tokens);
+        let mut stmts = proc_macro2::TokenStream::new();
+        self.to_statements(&mut stmts);
+        if let Some(otherwise) = &self.otherwise {
+            otherwise.to_statements(&mut stmts);
+        }
+        match &self.body {
+            Some(body) => tokens.extend(quote! { { #stmts #body } }),
+            None => tokens.extend(stmts),
+        }
+    }
+}
+
+impl CloneExprList {
+    /// Emits one `let` statement per entry, preceded by its shadowing
+    /// warnings, and one per group of entries sharing hoisted bases
+    fn to_statements(&self, tokens: &mut proc_macro2::TokenStream) {
+        let mut index = 0;
+        while index < self.exprs.len() {
+            if let Some(group) = self.hoisted.iter().find(|h| h.start == index) {
+                group.expand(&self.exprs, &self.shadowed, tokens);
+                index = group.end + 1;
+                continue;
+            }
+            for shadowed in self.shadowed.iter().filter(|s| s.index == index) {
+                shadowed.to_tokens(tokens);
+            }
+            self.exprs[index].to_tokens(tokens);
+            index += 1;
         }
     }
 }
@@ -261,17 +1253,431 @@ impl ToTokens for CloneExprList {
 /// - Tuple index: `clone!(tuple.0)` -> `let field_0 = tuple.0.clone();`
 /// - Nested tuple index: `clone!(obj.tuple.0)` -> `let field_0 = obj.tuple.0.clone();`
 /// - Method call: `clone!(obj.method())` -> `let method = obj.method().clone();`
+/// - Function call: `clone!(make_config())` -> `let make_config = make_config().clone();`
 /// - Nested method call: `clone!(a.b.method())` -> `let method = a.b.method().clone();`
 /// - Path/variable: `clone!(var)` -> `let var = var.clone();`
+/// - Trailing `?` and `.await`: `clone!(repo.fetch(id).await?)` -> `let fetch = repo.fetch(id).await?.clone();`
+///
+/// # References
+/// Bindings that are references, such as `x` in a `Some(ref x)` match arm or
+/// under match ergonomics, are cloned into owned values: for `x: &T` with
+/// `T: Clone`, method resolution picks `T::clone`, so `clone!(x)` binds a `T`.
+/// If `T` does not implement `Clone`, `x.clone()` copies the reference
+/// instead; `clone!(deref x as owned)` clones `*x` and fails to compile in that
+/// case.
+///
+/// The same applies in generic code, where whether `T` is `Clone` depends on
+/// the bounds: for `items: &Vec<T>`, `clone!(items)` binds a `Vec<T>` under
+/// `T: Clone` but copies the `&Vec<T>` without the bound. `deref` works with
+/// type parameters and turns the missing bound into an error. Generic
+/// arguments in paths do not affect the derived name:
+/// `clone!(Vec::<T>::new())` binds `new`.
+///
+/// Const generics are supported as well. Arrays are `Clone` for every
+/// length, so in `fn f<const N: usize>(arr: &[u8; N])`, `clone!(arr)` binds a
+/// `[u8; N]` without any bound. Const arguments in paths, such as
+/// `clone!(Buffer::<N>::new() as buffer)`, and array repeat expressions, such
+/// as `clone!([0; N] as zeros)`, work like any other source.
+///
+/// Smart pointers are cloned as a whole, as with a hand-written `.clone()`:
+/// `Pin<P>` is `Clone` when `P` is, so `clone!(pinned)` on a
+/// `Pin<Box<String>>` allocates a new box holding a copy of the string and
+/// binds a new `Pin<Box<String>>`. The original pinned value is not moved.
+///
+/// # Trait objects
+/// `Clone` is not object safe, so `dyn Trait` never implements it and
+/// `Box<dyn Trait>` does not either. Whether a source is a trait object is
+/// only known after type checking, so `clone!` cannot detect it: the error is
+/// the compiler's "method `clone` ... trait bounds were not satisfied",
+/// located at the source. For `borrowed: &dyn Trait`, `clone!(borrowed)`
+/// compiles but copies the reference, like any reference to a type that is
+/// not `Clone`.
+///
+/// Clone trait objects through a function with the `using` directive, e.g.
+/// `clone!(using dyn_clone::clone_box; shape as copy)` with the `dyn-clone`
+/// crate, or through a `box_clone()` method with [`box_clone!`].
 ///
 /// # Using `mut` modifier
 /// - `clone!(mut obj.field)` -> `let mut field = obj.field.clone();`
 /// - `clone!(mut tuple.0)` -> `let mut field_0 = tuple.0.clone();`
 /// - `clone!(mut a.b.c)` -> `let mut c = a.b.c.clone();`
 ///
+/// # Using `ref mut`
+/// - `clone!(ref mut buf)` -> `let mut __buf = buf.clone(); let buf = &mut __buf;`
+///
+/// The clone lives in a hidden local for the rest of the scope, so `buf` is a
+/// `&mut` that can be handed to APIs expecting one while the original stays
+/// untouched.
+///
+/// # Renaming with `as`
+/// - `clone!(obj.field as name)` -> `let name = obj.field.clone();`
+/// - `clone!(mut tuple.0 as first)` -> `let mut first = tuple.0.clone();`
+/// - `clone!(obj.field as "name")` -> `let name = obj.field.clone();` (the
+///   string must be a valid identifier; useful for macros that compute names)
+///
+/// Names that are keywords are written as raw identifiers, whether they come
+/// from the source, a rename or a directive: `clone!(token.r#type)` binds
+/// `r#type`, as do `clone!(kind as "type")` and `clone!(prefix = "ty"; pe)`.
+/// `self`, `Self`, `super` and `crate` cannot be raw identifiers, so an entry
+/// that would bind one of them, such as `clone!(self)`, is an error asking
+/// for another name.
+///
+/// A trailing `as <name>` is a rename only if the name is a single identifier
+/// that is not a primitive type. Anything else after `as`, such as `u64` or
+/// `Vec<u8>`, is a cast belonging to the source, which then needs a rename:
+/// - `clone!(x as u64 as wide)` -> `let wide = (x as u64).clone();`
+/// - `clone!(x as u64)` is an error rather than a binding named `u64`
+///
+/// With a rename, any expression can be cloned; it is parenthesized as needed
+/// to keep its precedence:
+/// - `clone!(a + b as sum)` -> `let sum = (a + b).clone();`
+/// - `clone!(v[0] as first)` -> `let first = v[0].clone();`
+/// - `clone!(42 as answer)` -> `let answer = 42;` (a literal is bound as is,
+///   since there is nothing to clone)
+/// - `clone!(vec![1, 2, 3] as v)` -> `let v = vec![1, 2, 3];` (so is the
+///   result of `vec!` and `format!`; other macro sources are cloned, e.g.
+///   `clone!(config!() as cfg)` -> `let cfg = config!().clone();`)
+///
+/// The whole expression is cloned, not its operands: `clone!(a + &b as ab)`
+/// still moves a `String` `a` into the addition. To keep it, clone the operand
+/// explicitly, e.g. `clone!(a.clone() + &b as ab)`.
+///
+/// # Cloning through another method with `via`
+/// For types that provide their own duplication method instead of (or in
+/// addition to) `Clone`, `via <method>` calls that method instead:
+/// - `clone!(state.current() via snapshot as snap)` -> `let snap = state.current().snapshot();`
+/// - `clone!(load_state() via snapshot as snap)` -> `let snap = load_state().snapshot();`
+///
+/// When several traits in scope provide a method of the same name, name the
+/// trait to call it with fully qualified syntax:
+/// - `clone!(state via <StateExt>::snapshot as snap)` -> `let snap = StateExt::snapshot(&state);`
+///
+/// Fully qualified calls do not auto-dereference, so the source must be the
+/// implementing type itself rather than a reference to it.
+///
+/// # Type annotations
+/// - `clone!(obj.field: String)` -> `let field: String = obj.field.clone();`
+/// - `clone!(obj.field as name: String)` -> `let name: String = obj.field.clone();`
+///
+/// # Modifiers
+/// Modifiers are written after `mut` and before the source expression:
+/// - `into_owned`: `clone!(into_owned cfg.path as path)` ->
+///   `let path = cfg.path.clone().into_owned();` (for `Cow` sources)
+/// - `take`: `clone!(take data.name as name)` -> `let name = data.name;`
+///   (moves the source instead of cloning it)
+/// - `replace`: `clone!(replace slot.value as old)` ->
+///   `let old = std::mem::replace(&mut slot.value, Default::default());`
+///   (takes the value out of a place that cannot be moved from, such as a
+///   field behind `&mut`, and leaves the type's default behind; the source
+///   must be mutable and its type must implement `Default`)
+/// - `cast`: `clone!(cast metrics.count as count: u64)` ->
+///   `let count: u64 = metrics.count.clone() as u64;` (requires a type annotation)
+/// - `upcast`: `clone!(upcast handle as base: Arc<dyn Base>)` ->
+///   `let base: Arc<dyn Base> = handle.clone();` (clones a pointer to a
+///   trait object such as `Arc<dyn Sub>` and relies on trait upcasting
+///   coercion to convert it to a pointer to a supertrait object; requires a
+///   type annotation naming the target)
+/// - `deref`: `clone!(deref guard as data)` -> `let data = (*guard).clone();`
+///   (clones the value behind a `Deref` type such as a lock guard rather than
+///   the wrapper itself)
+/// - `unsafe deref`: `clone!(unsafe deref ptr as value)` ->
+///   `let value = unsafe { (*ptr).clone() };` (for raw pointers; requires a
+///   rename). The caller must guarantee that `ptr` is non-null, properly
+///   aligned and points to an initialized value that is not being mutated
+///   concurrently, exactly as for a hand-written `*ptr` dereference.
+/// - `getter`: `clone!(getter obj.name as name)` ->
+///   `let name = obj.name().clone();` (calls a getter method named after the
+///   field, for types that keep the field private; the source must be a named
+///   field access)
+/// - `get`: `clone!(get CONFIG as config)` ->
+///   `let config = CONFIG.get().expect("`CONFIG` is not initialized").clone();`
+///   (for `OnceLock`/`OnceCell`; panics if the cell is empty). The message can
+///   be chosen with `get("message")`. A `LazyLock` initializes itself on
+///   first access, so `deref` is the matching modifier for it.
+/// - `map_clone`: `clone!(map_clone node.next as next)` ->
+///   `let next = node.next.as_ref().map(Clone::clone);` (for `Option` sources)
+/// - `collect`: `clone!(collect items.iter() as owned: Vec<String>)` ->
+///   `let owned: Vec<String> = IntoIterator::into_iter(items.iter()).cloned().collect();`
+///   (clones the items of an iterator, or of anything iterable by reference
+///   such as `&items`, into the annotated collection; requires a type
+///   annotation)
+/// - `view`: `clone!(view owned.text as text)` ->
+///   `let __text = owned.text.clone(); let text = &*__text;` (keeps the clone
+///   in a hidden local for the rest of the scope and binds a borrowed view of
+///   it through `Deref`, such as a `&str` for a `String` or a `&[T]` for a
+///   `Vec<T>`; only the view can be named)
+/// - `ref_pat`: `clone!(ref_pat cfg)` -> `let ref cfg = cfg.clone();` (binds
+///   a reference to the clone with a `ref` pattern; `mut ref_pat` gives
+///   `let ref mut`). Unlike a `&cfg` source, which clones through the
+///   reference, the clone is owned by the enclosing block and `cfg` borrows it.
+/// - `flatten`: `clone!(flatten nested as v)` -> `let v = nested.clone().flatten();`
+///   (for `Option<Option<T>>` sources; requires a rename)
+/// - `ok` / `err`: `clone!(ok resp.body as body)` ->
+///   `let body = resp.body.clone().ok();` (for `Result` sources; the binding
+///   is an `Option` of the `Ok` value, or of the `Err` value with `err`;
+///   requires a rename)
+/// - `q`: `clone!(q opt.field as field)` -> `let field = opt.field.clone()?;`
+///   (for `Option` and `Result` sources in functions returning a compatible
+///   `Option` or `Result`: the binding is the inner value and `None` or `Err`
+///   returns early. Unlike `or_else` on [`try_clone!`], which handles a
+///   failed clone in place, `q` propagates it to the caller. Modifiers
+///   written after `q` apply to the inner value)
+/// - `first`: `clone!(first items.iter() as head)` ->
+///   `let head = items.iter().next().cloned();` (clones the first item of an
+///   iterator source; the binding is an `Option` that is `None` for an empty
+///   iterator; requires a rename)
+/// - `cow_owned`: `clone!(cow_owned s.name as name: Cow<'static, str>)` ->
+///   `let name: Cow<'static, str> = Cow::Owned(s.name.clone());` (the opposite
+///   of `into_owned`). An owned `Cow` borrows nothing, so any lifetime,
+///   including `'static`, can be chosen. The borrowed type cannot be inferred
+///   from the clone alone: annotate the binding or pass it where a `Cow` of a
+///   known type is expected.
+/// - `cow_borrowed`: `clone!(cow_borrowed s as c: Cow<str>)` ->
+///   `let c: Cow<str> = Cow::Borrowed(s);` (the counterpart of `cow_owned`
+///   for when a borrow is enough). Nothing is cloned: the source must be a
+///   reference, such as a `&str` or `&cfg.name`, and the binding borrows from
+///   it, so the original cannot be moved or mutated while the binding is
+///   used. Choose `cow_owned` when the binding must outlive the source.
+/// - `boxed_str` / `boxed_slice`: `clone!(boxed_str s.name as name)` ->
+///   `let name = s.name.clone().into_boxed_str();` (binds a `Box<str>` for a
+///   `String`, or a `Box<[T]>` for a `Vec<T>` with `boxed_slice`; the clone's
+///   spare capacity is released, which suits data that is no longer modified)
+/// - `copy`: `clone!(copy handler.callback as callback)` ->
+///   `let callback = *&handler.callback;` (copies a `Copy` value such as a `fn`
+///   pointer without calling `clone`, which keeps clippy's `clone_on_copy`
+///   quiet; a non-`Copy` source fails to compile)
+/// - `wrap(Type)`: `clone!(wrap(Box) node as boxed)` ->
+///   `let boxed = Box::new(node.clone());` (any type with a `new` constructor
+///   taking the value works, e.g. `Box`, `Rc`, `Arc`, `Mutex`, `RefCell` or a
+///   path such as `std::sync::RwLock`)
+/// - `drop`: `clone!(drop big.data as data)` ->
+///   `let data = big.data.clone(); drop(big);` (frees the original as soon as
+///   the clone is made; the source must be a local variable or a field of
+///   one, and that variable cannot be used afterwards)
+/// - `timed`: `clone!(timed big.data as data)` -> `let data = { ... };`, a
+///   block that measures the clone with `std::time::Instant` and prints it to
+///   stderr if it took longer than 1ms. The measurement is compiled only with
+///   `debug_assertions`; release builds perform the plain clone.
+/// - `stamped`: `clone!(stamped data as entry)` ->
+///   `let entry = (data.clone(), std::time::Instant::now());` (pairs the clone
+///   with the time it was made, e.g. for cache entries that expire; the
+///   binding is a `(T, Instant)` tuple, so a rename is required)
+/// - `lock` / `read`: `clone!(lock state as data)` ->
+///   `let data = { let guard = state.lock().expect(..); (*guard).clone() };`
+///   (clones the value inside a `Mutex`, or an `RwLock` through a read guard
+///   with `read`; the guard is dropped as soon as the clone is made, and a
+///   poisoned lock panics). [`clone_guard!`] applies `lock` by default.
+///   Followed by `&`, `*` or `<`, `lock` and `read` are variables instead:
+///   `clone!(read & mask as bits)` clones `read & mask`.
+/// - `black_box`: `clone!(black_box data)` ->
+///   `let data = std::hint::black_box(data.clone());` (keeps the optimizer
+///   from removing a clone whose result is unused; intended for benchmark
+///   code only, since it also blocks optimizations of the surrounding code)
+/// - `sorted` / `sorted_by(compare)`: `clone!(sorted data.items as items)` ->
+///   `let mut items = data.items.clone(); items.sort();` (sorts the clone,
+///   which requires `T: Ord` for `sorted`; `sorted_by(|a, b| b.cmp(a))` calls
+///   `sort_by` with the comparator instead; the binding is always mutable)
+/// - `retain(predicate)`: `clone!(retain(|x| *x > 0) data.nums as nums)` ->
+///   `let mut nums = data.nums.clone(); nums.retain(|x| *x > 0);` (keeps the
+///   elements matching the predicate, for any collection with a `retain`
+///   method such as `Vec`, `String` or `HashMap`; the binding is always
+///   mutable)
+/// - `dedup` / `reverse`: `clone!(dedup data.items as items)` ->
+///   `let mut items = data.items.clone(); items.dedup();` (removes consecutive
+///   duplicates, or reverses the order with `reverse`; the binding is always
+///   mutable)
+/// - `shrink`: `clone!(shrink cache as c)` ->
+///   `let mut c = cache.clone(); c.shrink_to_fit();` (releases spare capacity
+///   of a `Vec`, `String`, `HashMap` or other collection with
+///   `shrink_to_fit`, e.g. after `dedup`; the binding is always mutable)
+/// - `reserve(n)`: `clone!(reserve(16) data.items as items)` ->
+///   `let mut items = data.items.clone(); items.reserve(16);` (reserves extra
+///   capacity in the clone; the binding is always mutable)
+///
+/// Modifiers that work on the binding after the clone (`sorted`,
+/// `sorted_by`, `retain`, `dedup`, `reverse`, `shrink` and `reserve`) run in the order
+/// they are written, so they form a small pipeline:
+/// - `clone!(sorted dedup data.items as items)` ->
+///   `let mut items = data.items.clone(); items.sort(); items.dedup();`
+///
+/// # Post-conditions with `where`
+/// A trailing `where <predicate>` checks the clone with `debug_assert!`:
+/// - `clone!(cfg where |c| c.is_valid())` -> `let cfg = cfg.clone();` followed
+///   by a `debug_assert!` that calls the predicate with `&cfg`
+///
+/// The predicate receives a reference to the clone and can be a closure or a
+/// function such as `Config::is_valid`. Like any `debug_assert!`, the check
+/// only runs when `debug_assertions` are enabled; release builds still
+/// type-check the predicate but never call it.
+///
 /// # Multiple expressions
 /// - `clone!(a, b.field, mut c)` -> generates multiple let statements
 ///
+/// # Expansion
+/// Without modifiers, each entry expands to exactly the statement shown above
+/// with no wrappers, so `clone!` has no overhead compared to hand-written code.
+///
+/// # Spans
+/// Generated code carries the spans of the tokens it comes from, so IDEs
+/// and compiler diagnostics map it back to the invocation:
+/// - A derived binding name is the source's own last token, e.g. `name` in
+///   `clone!(person.name)`, and a rename is the token written after `as`.
+///   Go to definition on a later use of the binding lands on that token, and
+///   hovering it shows the binding's type.
+/// - The `clone` call is located at the source, so an error such as a
+///   missing `Clone` impl points at the entry rather than at the whole
+///   macro invocation.
+/// - A source ending with `.clone()` or `.to_owned()`, as in
+///   `clone!(x.clone() as y)`, would be cloned twice. This is reported as a
+///   deprecation warning at the method name, or as an error with the
+///   `strict` directive. Entries with `via` or a modifier that replaces the
+///   clone are not reported.
+///
+/// # Placement
+/// `clone!` expands to plain `let` statements, so it can be used anywhere a
+/// statement can, including labeled blocks (`'outer: { clone!(x); ... }`) and
+/// loop bodies. The bindings are scoped to the enclosing block.
+///
+/// The statements are complete on their own, so a binding can be returned
+/// as the trailing expression of the enclosing block or function:
+/// - `fn snapshot(cfg: &Config) -> Config { clone!(cfg); cfg }`
+///
+/// # Const contexts
+/// `Clone::clone` is not a `const fn`, so `clone!` fails to compile inside
+/// `const` items and `const fn`s with "cannot call non-const method". Types
+/// that provide a `const fn` duplication method can be cloned there with
+/// `via`, since the entry then calls only that method:
+/// - `const COPY: Limits = { clone!(DEFAULT via duplicate as limits); limits };`
+///
+/// # `no_std`
+/// The generated code only refers to `core`, so `clone!` and the other macros
+/// of this crate work in `#![no_std]` crates, with or without `alloc`. The
+/// exceptions are modifiers that need the standard library by nature:
+/// `cow_owned` and `cow_borrowed` (`std::borrow::Cow`), `stamped` (`std::time::Instant`) and
+/// `timed` (`std::time::Instant` and `eprintln!`), as well as the
+/// [`spawn_clone!`] (`std::thread`), [`shared!`] and [`share_clone!`]
+/// (`std::rc::Rc`) macros.
+///
+/// # Evaluation order
+/// Entries are expanded into one `let` statement each, in the order they are
+/// written. Every source expression is therefore evaluated exactly once, left
+/// to right, and each clone happens before the next source is evaluated.
+///
+/// Because the statements are emitted top to bottom, later entries can use
+/// the bindings of earlier ones, e.g. `clone!(cfg.name, name.len() as len)`
+/// measures the clone. An earlier binding also shadows a variable of the same
+/// name for the entries after it; since that is easy to miss, a source that
+/// reads a variable replaced this way, as `config` in
+/// `clone!(config.name as config, config.port)`, gets a deprecation warning
+/// "`config` refers to the clone bound by an earlier entry of this macro".
+/// Put `#[allow(deprecated)]` on the entry if the shadowing is intended.
+///
+/// A computed base written in several entries, as `record()` in
+/// `clone!(record().1 as name, record().3 as email)`, is called once per
+/// entry, since two equal calls may return different values. The `hoist`
+/// directive shares it instead: `clone!(hoist; record().1 as name,
+/// record().3 as email)` calls `record()` once and clones both fields from
+/// the result, in a single statement
+/// `let (name, email) = { let __base0 = record(); ... };`. The value and its
+/// temporaries, such as a `RefCell` borrow or a lock guard, are dropped at
+/// the end of that statement. Variables and field chains such as `record.1`
+/// are never hoisted, and neither are bases of entries with different
+/// attributes or of entries binding a reference (`ref mut`, `ref`, `view`).
+/// [`clone_tuple!`] and [`clone_fields!`], whose source is written once,
+/// always share it.
+///
+/// # Directives
+/// Directives are written before the entries and terminated by `;`. They
+/// apply to every entry of the invocation and can be combined in any order:
+///
+/// ```text
+/// clone!([#[attr]... =>] [#[cfg(..)]... directive...;] [directive...;] entry, entry, ...)
+/// ```
+///
+/// - `mut`: makes every binding mutable
+/// - `prefix = "..."`: prepends a string to every binding name
+/// - `wrap = Type`: wraps every clone in `Type::new`, like the `wrap(Type)`
+///   modifier; e.g. `clone!(wrap = Arc; state as s)` ->
+///   `let s = Arc::new(state.clone());`
+/// - `max_size = N`: fails to compile if a cloned value is larger than `N`
+///   bytes (as reported by `size_of`), to catch accidental clones of large
+///   types. The type is only known after type inference, so the check runs
+///   when the code is compiled to a binary: `cargo build` and `cargo test`
+///   report it, `cargo check` does not. It measures the value itself, not
+///   heap data it owns, so a `Vec` is always 24 bytes on 64-bit targets.
+/// - `send`: fails to compile if a cloned value is not `Send`, e.g.
+///   `clone!(send; state)` rejects an `Rc`. Useful right before moving the
+///   clones into `std::thread::spawn`, where the error would otherwise point
+///   at the closure instead of the clone.
+/// - `strict`: turns the redundant-clone warning (see [Spans](#spans)) into
+///   an error, e.g. `clone!(strict; x.clone() as y)` fails to compile.
+/// - `using <path>`: calls a free function with a reference to each source
+///   instead of its clone method, for clone frameworks such as `dyn-clone`;
+///   e.g. `clone!(using dyn_clone::clone_box; widget as w)` ->
+///   `let w = dyn_clone::clone_box(&widget);`. An entry's own `via` clause
+///   takes precedence.
+/// - `intern = <path>`: calls an interner instead of the clone method, so
+///   equal values share one allocation; e.g.
+///   `clone!(intern = intern_fn; s.name as name)` ->
+///   `let name = intern_fn(&s.name);`. The function receives a reference to
+///   the source (`&T`, not `T`) and returns the interned value, such as an
+///   `Arc<str>`. This is `using` under a name that states the intent; the two
+///   cannot be combined.
+/// - `suffix_index`: appends the zero-based position of each entry to its
+///   binding name, e.g. `clone!(suffix_index; a, b.c)` ->
+///   `let a_0 = a.clone(); let c_1 = b.c.clone();`. Useful for bindings
+///   generated by other macros. Applied after `prefix`.
+/// - `hoist`: evaluates a computed base read by several entries once, e.g.
+///   `clone!(hoist; load().1 as name, load().3 as email)` calls `load()`
+///   once. See [Evaluation order](#evaluation-order).
+/// - `full_name`: names the binding of a multi-segment path source after all
+///   of its segments, e.g. `clone!(full_name; config::database::URL)` ->
+///   `let config_database_URL = config::database::URL.clone();`. Other
+///   sources and renamed entries keep their name.
+/// - `must_use`: marks every `let` with `#[warn(unused_variables)]`, so a
+///   clone that is never used is reported even where that lint is allowed,
+///   e.g. by `#![allow(unused)]`. A `let` binding cannot carry `#[must_use]`
+///   itself; this is the closest equivalent. Bindings starting with `_` are
+///   never reported.
+/// - `trace`: logs every clone with `tracing::trace!("cloning <source>")`.
+///   Requires the `tracing` feature of this crate, and the calling crate must
+///   depend on `tracing`; without the feature the directive is an error.
+/// - `warn("message")`: reports a deprecation warning with the message at
+///   every clone, e.g. `clone!(warn("use Arc instead"); big)` warns
+///   "use of deprecated function `..::clone_warning`: use Arc instead" at
+///   `big`. Useful to steer callers away from expensive clones without
+///   breaking their build; `#[allow(deprecated)]` silences it.
+/// - `count` / `count = COUNTER`: increments a counter before every clone
+///   with `COUNTER.fetch_add(1, Relaxed)`, to measure how often a code path
+///   clones. A proc-macro crate cannot export the counter itself, so the
+///   calling crate defines it, e.g.
+///   `static LETCLONE_COUNTER: AtomicUsize = AtomicUsize::new(0);`; a bare
+///   `count` uses the `LETCLONE_COUNTER` in scope. Requires the `metrics`
+///   feature of this crate; without it the directive is an error.
+///
+/// For example, `clone!(mut prefix = "new_"; form.title, form.body)` expands
+/// to `let mut new_title = form.title.clone(); let mut new_body = form.body.clone();`
+///
+/// A directive block preceded by `#[cfg(...)]` attributes applies only when
+/// the predicate holds, in addition to an optional unconditional block after
+/// it. A proc macro cannot evaluate `cfg`, so both variants are expanded and
+/// every statement is gated:
+/// - `clone!(#[cfg(feature = "sync")] wrap = Arc; state as s)` ->
+///   `#[cfg(feature = "sync")] let s = Arc::new(state.clone()); #[cfg(not(feature = "sync"))] let s = state.clone();`
+///
+/// Several `cfg` attributes must all hold. Only `cfg` can gate a block.
+///
+/// # Attributes
+/// Outer attributes written before an entry are re-emitted on its `let`:
+/// - `clone!(#[allow(unused)] temp, real)` -> `#[allow(unused)] let temp = temp.clone(); let real = real.clone();`
+///
+/// Attributes are re-emitted verbatim, so conditional `#[cfg_attr(...)]`
+/// attributes apply only in the configurations they name:
+/// - `clone!(#[cfg_attr(test, allow(unused))] temp)` -> `#[cfg_attr(test, allow(unused))] let temp = temp.clone();`
+///
+/// Outer attributes followed by `=>` are applied to every generated `let`:
+/// - `clone!(#[cfg(test)] => a, b)` -> `#[cfg(test)] let a = a.clone(); #[cfg(test)] let b = b.clone();`
+///
 /// # Usage in closures
 /// The macro is particularly useful for cloning values before moving them into closures:
 /// ```
@@ -287,6 +1693,15 @@ impl ToTokens for CloneExprList {
 ///     }
 /// };
 /// ```
+///
+/// A closure or async block written after `=>` is made `move` and returned
+/// together with the clones it captures:
+/// - `clone!(name, scores => || println!("{name}"))` ->
+///   `{ let name = name.clone(); let scores = scores.clone(); move || println!("{name}") }`
+/// - `clone!(state => async move { state.process().await })` ->
+///   `{ let state = state.clone(); async move { state.process().await } }`
+///
+/// The body must be the last item of the invocation.
 #[proc_macro]
 pub fn clone(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     let expr_list = syn::parse_macro_input!(input as CloneExprList);
@@ -294,3 +1709,959 @@ pub fn clone(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     expr_list.to_tokens(&mut tokens);
     proc_macro::TokenStream::from(tokens)
 }
+
+/// Clones a key/value pair, typically while iterating over a map
+///
+/// # Forms
+/// - `clone_kv!(map => k, v)` -> `let k = k.clone(); let v = v.clone();`
+///
+///   Intended for the loop variables of `for (k, v) in &map`. The map
+///   expression only documents where the pair comes from; it is not evaluated.
+/// - `clone_kv!(entry as (k, v))` -> destructures a `(&K, &V)` tuple and
+///   binds owned clones of both halves
+///
+/// # Example
+/// ```
+/// use letclone::clone_kv;
+/// use std::collections::HashMap;
+///
+/// let mut map = HashMap::new();
+/// map.insert(String::from("key"), String::from("value"));
+///
+/// if let Some(entry) = map.get_key_value("key") {
+///     clone_kv!(entry as (k, v));
+///     let owned: (String, String) = (k, v);
+///     assert_eq!(owned, (String::from("key"), String::from("value")));
+/// }
+/// ```
+#[proc_macro]
+pub fn clone_kv(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_kv = syn::parse_macro_input!(input as clone_kv::CloneKv);
+    proc_macro::TokenStream::from(clone_kv.to_token_stream())
+}
+
+/// Clones a list of named fields of a struct into bindings of the same name
+///
+/// # Forms
+/// - `clone_fields!(person => name, email)` ->
+///   `let name = person.name.clone(); let email = person.email.clone();`
+/// - `clone_fields!(person => mut name, email, !id, !internal)` -> clones
+///   `name` (into a mutable binding) and `email`, and skips `id` and
+///   `internal`
+///
+/// A proc macro only sees the tokens of its input, not the definition of the
+/// struct, so the fields to clone must be listed: `*` for "all fields" is not
+/// supported. `!field` entries record which fields are left out on purpose;
+/// they are not cloned, but still fail to compile if the field does not
+/// exist, so the list is checked when the struct changes. A computed source
+/// such as `load()` is evaluated once, as with [`clone!`].
+///
+/// # Example
+/// ```
+/// use letclone::clone_fields;
+///
+/// struct Person {
+///     id: u64,
+///     name: String,
+///     email: String,
+/// }
+///
+/// let person = Person { id: 1, name: String::from("Ann"), email: String::from("ann@example.com") };
+/// clone_fields!(person => name, email, !id);
+/// assert_eq!((name.as_str(), email.as_str()), ("Ann", "ann@example.com"));
+/// ```
+#[proc_macro]
+pub fn clone_fields(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_fields = syn::parse_macro_input!(input as clone_fields::CloneFields);
+    proc_macro::TokenStream::from(clone_fields.to_token_stream())
+}
+
+/// Builds a struct whose listed fields are clones of the same fields of a
+/// source
+///
+/// # Forms
+/// - `clone_struct!(Foo { a, b } from src)` ->
+///   `Foo { a: src.a.clone(), b: src.b.clone() }`
+/// - `clone_struct!(Foo { a, ..base } from src)` ->
+///   `Foo { a: src.a.clone(), ..base }`
+/// - `clone_struct!(Foo { a, b } from src, ..default)` ->
+///   `Foo { a: src.a.clone(), b: src.b.clone(), ..Default::default() }`
+///
+/// The macro is an expression. The source is borrowed once, so a computed
+/// source such as `load()` is evaluated once, and the source may be of a
+/// different type than the struct being built as long as it has the listed
+/// fields. `..default` requires the struct to implement `Default` and cannot
+/// be combined with an explicit `..base`.
+///
+/// # Example
+/// ```
+/// use letclone::clone_struct;
+///
+/// #[derive(Default)]
+/// struct Request {
+///     url: String,
+///     retries: u32,
+/// }
+///
+/// let template = Request { url: String::from("https://example.com"), retries: 3 };
+/// let request = clone_struct!(Request { url } from template, ..default);
+/// assert_eq!((request.url.as_str(), request.retries), ("https://example.com", 0));
+/// ```
+#[proc_macro]
+pub fn clone_struct(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_struct = syn::parse_macro_input!(input as clone_struct::CloneStruct);
+    proc_macro::TokenStream::from(clone_struct.to_token_stream())
+}
+
+/// Clones the positional fields of a tuple or tuple struct into named
+/// bindings
+///
+/// # Forms
+/// - `clone_tuple!(point => x, y, z)` ->
+///   `let x = point.0.clone(); let y = point.1.clone(); let z = point.2.clone();`
+/// - `clone_tuple!(point => mut x, y)` -> `let mut x = point.0.clone(); ...`
+///
+/// Names are mapped to `.0`, `.1`, ... in order; trailing fields can be left
+/// out. The arity of the tuple is unknown to the macro, so naming more fields
+/// than it has is reported by the compiler as a missing field. A computed
+/// source such as `load()` is evaluated once, as with [`clone!`].
+///
+/// # Example
+/// ```
+/// use letclone::clone_tuple;
+///
+/// struct Point(f64, f64, f64);
+///
+/// let point = Point(1.0, 2.0, 3.0);
+/// clone_tuple!(point => x, y, z);
+/// assert_eq!(x + y + z, 6.0);
+/// ```
+#[proc_macro]
+pub fn clone_tuple(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_tuple = syn::parse_macro_input!(input as clone_tuple::CloneTuple);
+    proc_macro::TokenStream::from(clone_tuple.to_token_stream())
+}
+
+/// Snapshots the current values of atomics with `load`
+///
+/// Reading an atomic is a load, not a clone, but it answers the same need of
+/// taking a snapshot of shared state. This macro accepts the same entries as
+/// [`clone!`] and calls `load` with a memory ordering, `Relaxed` unless one is
+/// given before a `;`.
+///
+/// # Forms
+/// - `atomic_load!(counter as n)` ->
+///   `let n = counter.load(core::sync::atomic::Ordering::Relaxed);`
+/// - `atomic_load!(Acquire; stats.hits, stats.misses)` -> loads both fields
+///   with `Ordering::Acquire`
+///
+/// Loads accept `Relaxed`, `Acquire` and `SeqCst`. Directives of [`clone!`]
+/// such as `send;` may follow the ordering or stand in its place.
+/// Modifiers that replace the clone, such as `take`, `via` clauses and the
+/// `using` directive would skip the load and are rejected.
+///
+/// # Example
+/// ```
+/// use letclone::atomic_load;
+/// use std::sync::Arc;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+///
+/// let counter = Arc::new(AtomicUsize::new(0));
+/// counter.fetch_add(2, Ordering::Relaxed);
+///
+/// atomic_load!(SeqCst; counter as n);
+/// assert_eq!(n, 2);
+/// ```
+#[proc_macro]
+pub fn atomic_load(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let atomic_load = syn::parse_macro_input!(input as atomic_load::AtomicLoad);
+    proc_macro::TokenStream::from(atomic_load.to_token_stream())
+}
+
+/// Clones boxed trait objects through a `box_clone()` method
+///
+/// `Clone` is not object safe, so traits used as `Box<dyn Trait>` commonly
+/// provide a `fn box_clone(&self) -> Box<dyn Trait>` method instead. This
+/// macro accepts the same entries as [`clone!`] but calls that method.
+///
+/// # Forms
+/// - `box_clone!(obj)` -> `let obj = obj.box_clone();`
+/// - `box_clone!(holder.widget as w)` -> `let w = holder.widget.box_clone();`
+/// - `box_clone!(via duplicate; obj)` -> `let obj = obj.duplicate();`
+///
+/// Modifiers that replace the clone, such as `take`, and `via` clauses on the
+/// entries would skip the method and are rejected.
+///
+/// # Example
+/// ```
+/// use letclone::box_clone;
+///
+/// trait Shape {
+///     fn area(&self) -> f64;
+///     fn box_clone(&self) -> Box<dyn Shape>;
+/// }
+///
+/// #[derive(Clone)]
+/// struct Square(f64);
+///
+/// impl Shape for Square {
+///     fn area(&self) -> f64 {
+///         self.0 * self.0
+///     }
+///     fn box_clone(&self) -> Box<dyn Shape> {
+///         Box::new(self.clone())
+///     }
+/// }
+///
+/// let shape: Box<dyn Shape> = Box::new(Square(2.0));
+/// box_clone!(shape as copy);
+/// assert_eq!(copy.area(), 4.0);
+/// ```
+#[proc_macro]
+pub fn box_clone(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let box_clone = syn::parse_macro_input!(input as box_clone::BoxClone);
+    proc_macro::TokenStream::from(box_clone.to_token_stream())
+}
+
+/// Clones values through a fallible `try_clone()` method and propagates the
+/// error with `?`
+///
+/// Types such as `std::fs::File` and `std::net::TcpStream` cannot implement
+/// `Clone` because duplicating them can fail; they provide
+/// `fn try_clone(&self) -> Result<Self, E>` instead. This macro accepts the
+/// same entries as [`clone!`] but calls that method, so it can only be used
+/// in functions returning a compatible `Result`.
+///
+/// # Forms
+/// - `try_clone!(file)` -> `let file = file.try_clone()?;`
+/// - `try_clone!(log.file as f)` -> `let f = log.file.try_clone()?;`
+/// - `try_clone!(file context "cloning log file" as f)` ->
+///   `let f = anyhow::Context::context(file.try_clone(), "cloning log file")?;`
+///
+///   Requires the `anyhow` feature of this crate, and the calling crate must
+///   depend on `anyhow`; without the feature `context` is an error.
+/// - `try_clone!(file or_else { return Ok(()) } as f)` ->
+///   `let f = match file.try_clone() { Ok(v) => v, Err(_) => { return Ok(()) } };`
+///
+///   The block runs instead of propagating the error, so it must diverge
+///   (`return`, `break`, `continue`, `panic!`) or evaluate to a fallback
+///   value. It does not need the enclosing function to return a `Result`.
+///
+/// Modifiers that replace the clone, such as `take` or `copy`, and `via`
+/// clauses would make the clone infallible and are rejected.
+///
+/// # Example
+/// ```
+/// use letclone::try_clone;
+/// use std::net::UdpSocket;
+///
+/// fn duplicate(socket: &UdpSocket) -> std::io::Result<UdpSocket> {
+///     try_clone!(socket as copy);
+///     Ok(copy)
+/// }
+///
+/// let socket = UdpSocket::bind("127.0.0.1:0").unwrap();
+/// let copy = duplicate(&socket).unwrap();
+/// assert_eq!(copy.local_addr().unwrap(), socket.local_addr().unwrap());
+/// ```
+#[proc_macro]
+pub fn try_clone(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let try_clone = syn::parse_macro_input!(input as try_clone::TryClone);
+    proc_macro::TokenStream::from(try_clone.to_token_stream())
+}
+
+/// Clones values and converts each clone into its annotated type with
+/// `TryFrom`, propagating the conversion error with `?`
+///
+/// Every entry needs a `: Type` annotation naming the target type, and the
+/// macro can only be used in functions returning a `Result` whose error type
+/// the conversion error converts into. Other modifiers apply to the
+/// converted value.
+///
+/// # Forms
+/// - `try_clone_from!(raw: Parsed)` ->
+///   `let raw: Parsed = <Parsed as TryFrom<_>>::try_from(raw.clone())?;`
+/// - `try_clone_from!(config.port as port: u16)` ->
+///   `let port: u16 = <u16 as TryFrom<_>>::try_from(config.port.clone())?;`
+///
+/// # Example
+/// ```
+/// use letclone::try_clone_from;
+///
+/// fn narrow(wide: &i64) -> Result<u8, std::num::TryFromIntError> {
+///     try_clone_from!(*wide as narrow: u8);
+///     Ok(narrow)
+/// }
+///
+/// assert_eq!(narrow(&200), Ok(200));
+/// assert!(narrow(&300).is_err());
+/// ```
+#[proc_macro]
+pub fn try_clone_from(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let try_clone_from = syn::parse_macro_input!(input as try_clone_from::TryCloneFrom);
+    proc_macro::TokenStream::from(try_clone_from.to_token_stream())
+}
+
+/// Clones the values inside locks, releasing each guard right after the clone
+///
+/// Holding a guard while working with the cloned data needlessly blocks
+/// other threads. This macro accepts the same entries as [`clone!`] but
+/// clones through a `lock()` guard scoped to the clone itself, so the lock
+/// is held only while cloning and the binding owns its data. Prefix an entry
+/// with `read` to clone out of an `RwLock` instead.
+///
+/// # Forms
+/// - `clone_guard!(mutex as data)` ->
+///   `let data = { let guard = mutex.lock().expect(..); (*guard).clone() };`
+/// - `clone_guard!(read cache as entries)` ->
+///   `let entries = { let guard = cache.read().expect(..); (*guard).clone() };`
+///
+/// Works with the standard `Mutex` and `RwLock`. A poisoned lock panics with
+/// a message naming the source.
+/// The guard already replaces the clone, so entries cannot use another
+/// modifier that does, such as `take`, or a `via` clause.
+///
+/// # Example
+/// ```
+/// use letclone::clone_guard;
+/// use std::sync::{Arc, Mutex, RwLock};
+///
+/// let queue = Arc::new(Mutex::new(vec![1, 2]));
+/// let config = RwLock::new(String::from("debug"));
+///
+/// clone_guard!(queue as jobs, read config as level);
+/// // Both locks are already released
+/// queue.lock().unwrap().push(3);
+/// assert_eq!(jobs, [1, 2]);
+/// assert_eq!(level, "debug");
+/// ```
+#[proc_macro]
+pub fn clone_guard(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_guard = syn::parse_macro_input!(input as clone_guard::CloneGuard);
+    proc_macro::TokenStream::from(clone_guard.to_token_stream())
+}
+
+/// Clones the variables a closure captures and makes it `move`
+///
+/// `autoclone!(|| ...)` expands to a block that clones every variable the
+/// closure appears to use and then evaluates to the closure as `move`, so it
+/// owns the clones and the originals stay usable:
+/// - `autoclone!(move || use_a(&a, b.field))` ->
+///   `{ let a = a.clone(); let b = b.clone(); move || use_a(&a, b.field) }`
+///
+/// # Heuristic
+/// A proc macro cannot resolve names, so captures are found by looking at the
+/// tokens of the closure:
+/// - a capture is a single-segment path starting with a lowercase letter or
+///   `_` used as a value, e.g. `a` in `a.len()` or `&a`; `self`, paths such as
+///   `std::mem::take`, upper-case names (types, constants, statics) and the
+///   callee of a call `f(x)` are not cloned
+/// - a name bound by any pattern inside the closure, including its
+///   parameters, `let`s and `match` arms, is never cloned, even where it
+///   refers to an outer variable
+/// - arguments of macros that take comma-separated expressions, such as
+///   `println!("{}", a)`, are inspected; variables only named inside format
+///   strings (`"{a}"`) or in other macro bodies are not
+///
+/// Every capture must implement `Clone`. When the heuristic does not fit, list
+/// the clones explicitly with [`clone!`].
+///
+/// # Example
+/// ```
+/// use letclone::autoclone;
+///
+/// let name = String::from("worker");
+/// let jobs = vec![1, 2, 3];
+///
+/// let handle = std::thread::spawn(autoclone!(|| format!("{}: {}", name, jobs.len())));
+/// assert_eq!(handle.join().unwrap(), "worker: 3");
+/// assert_eq!(name, "worker");
+/// ```
+#[proc_macro]
+pub fn autoclone(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let autoclone = syn::parse_macro_input!(input as autoclone::AutoClone);
+    proc_macro::TokenStream::from(autoclone.to_token_stream())
+}
+
+/// Clones values and spawns a thread that uses the clones
+///
+/// Accepts the same entries as [`clone!`], followed by `=>` and the body of
+/// the thread. The body runs in a `move` closure passed to
+/// `std::thread::spawn`, so it owns the clones while the originals stay usable
+/// on the spawning thread. The macro evaluates to the `JoinHandle`.
+///
+/// # Forms
+/// - `spawn_clone!(a, b => { body })` ->
+///   `{ let a = a.clone(); let b = b.clone(); std::thread::spawn(move || { body }) }`
+/// - `spawn_clone!(state.jobs as jobs => { ... })` -> renames, modifiers and
+///   directives work as in `clone!`
+///
+/// # Example
+/// ```
+/// use letclone::spawn_clone;
+/// use std::sync::{Arc, Mutex};
+///
+/// let counter = Arc::new(Mutex::new(0));
+/// let handle = spawn_clone!(counter => {
+///     *counter.lock().unwrap() += 1;
+/// });
+/// handle.join().unwrap();
+/// assert_eq!(*counter.lock().unwrap(), 1);
+/// ```
+#[proc_macro]
+pub fn spawn_clone(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let spawn_clone = syn::parse_macro_input!(input as spawn_clone::SpawnClone);
+    proc_macro::TokenStream::from(spawn_clone.to_token_stream())
+}
+
+/// Clones values into new shared mutable cells, `Rc<RefCell<T>>`
+///
+/// Accepts the same entries as [`clone!`]. Each clone is wrapped in a
+/// `RefCell` and then an `Rc`, the usual handle for single-threaded shared
+/// mutable state such as graph nodes or interpreter environments. Use
+/// [`share_clone!`] to give the handle to another owner.
+///
+/// # Forms
+/// - `shared!(value as handle)` ->
+///   `let handle = Rc::new(RefCell::new(value.clone()));`
+/// - `shared!(a, b.nodes as nodes)` -> one handle per entry, with renames,
+///   directives and modifiers as in `clone!`
+///
+/// # Example
+/// ```
+/// use letclone::shared;
+///
+/// let initial = vec![1, 2];
+/// shared!(initial as items);
+/// items.borrow_mut().push(3);
+/// assert_eq!(*items.borrow(), [1, 2, 3]);
+/// assert_eq!(initial, [1, 2]);
+/// ```
+#[proc_macro]
+pub fn shared(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let shared = syn::parse_macro_input!(input as shared::Shared);
+    proc_macro::TokenStream::from(shared.to_token_stream())
+}
+
+/// Clones `Rc` handles so that several owners share one value
+///
+/// Accepts the same entries as [`clone!`] but clones each source with
+/// `Rc::clone`, which only increments the reference count and fails to
+/// compile for anything that is not an `Rc`. The handles of [`shared!`] are
+/// the typical source.
+///
+/// # Forms
+/// - `share_clone!(handle)` -> `let handle = Rc::clone(&handle);`
+/// - `share_clone!(node.parent as parent)` ->
+///   `let parent = Rc::clone(&node.parent);`
+///
+/// An entry's own `via` clause takes precedence.
+///
+/// # Example
+/// ```
+/// use letclone::{share_clone, shared};
+///
+/// let start = 0;
+/// shared!(start as count);
+/// let increment = {
+///     share_clone!(count);
+///     move || *count.borrow_mut() += 1
+/// };
+/// increment();
+/// *count.borrow_mut() += 10;
+/// assert_eq!(*count.borrow(), 11);
+/// ```
+#[proc_macro]
+pub fn share_clone(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let share_clone = syn::parse_macro_input!(input as shared::ShareClone);
+    proc_macro::TokenStream::from(share_clone.to_token_stream())
+}
+
+/// Clones values in expression position
+///
+/// Unlike [`clone!`], this is an expression macro and introduces no bindings.
+/// It is meant for passing clones by value, e.g. as arguments of a function
+/// or of `format!`.
+///
+/// # Forms
+/// - `cloned!(a)` -> `a.clone()` (a single entry is the bare clone)
+/// - `cloned!(a, b.c)` -> `(a.clone(), b.c.clone())`
+///
+/// Any expression can be an entry; operators and casts are parenthesized,
+/// e.g. `cloned!(*r)` -> `(*r).clone()`.
+///
+/// # Example
+/// ```
+/// use letclone::cloned;
+///
+/// fn consume(name: String, tags: Vec<String>) -> usize {
+///     name.len() + tags.len()
+/// }
+///
+/// let name = String::from("app");
+/// let tags = vec![String::from("cli")];
+///
+/// assert_eq!(consume(cloned!(name), cloned!(tags)), 4);
+/// assert_eq!(format!("{}: {:?}", cloned!(name), cloned!(tags)), "app: [\"cli\"]");
+/// let (n, t) = cloned!(name, tags);
+/// assert_eq!((n, t.len()), (name, 1));
+/// ```
+#[proc_macro]
+pub fn cloned(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let cloned = syn::parse_macro_input!(input as cloned::Cloned);
+    proc_macro::TokenStream::from(cloned.to_token_stream())
+}
+
+/// Clones several values into a fixed-size array
+///
+/// Useful when all entries share a type and a `[T; N]` is wanted. Unlike
+/// [`clone!`], this is an expression macro and introduces no bindings.
+///
+/// # Forms
+/// - `clone_arr!(a, b, c)` -> `[a.clone(), b.clone(), c.clone()]`
+/// - `clone_arr!(x)` -> `[x.clone()]` (a single entry still yields an array)
+///
+/// Any expression can be an entry, e.g. `clone_arr!(cfg.name, names[0])`.
+///
+/// # Example
+/// ```
+/// use letclone::clone_arr;
+///
+/// let first = String::from("a");
+/// let second = String::from("b");
+///
+/// let all: [String; 2] = clone_arr!(first, second);
+/// assert_eq!(all, ["a", "b"]);
+/// assert_eq!(first, "a");
+/// ```
+#[proc_macro]
+pub fn clone_arr(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_arr = syn::parse_macro_input!(input as clone_arr::CloneArr);
+    proc_macro::TokenStream::from(clone_arr.to_token_stream())
+}
+
+/// Assigns clones to existing places
+///
+/// # Forms
+/// - `clone_assign!(target.field = source)` -> `target.field = source.clone();`
+/// - `clone_assign!(items[0] = cfg.name, total = cfg.count)` -> one assignment
+///   per entry, in order
+///
+/// The left-hand side can be any assignable place, such as a variable, a
+/// field or an indexed element. The source can be any expression.
+///
+/// # Example
+/// ```
+/// use letclone::clone_assign;
+///
+/// struct Config {
+///     name: String,
+/// }
+///
+/// let defaults = Config { name: String::from("default") };
+/// let mut config = Config { name: String::new() };
+/// let mut history = vec![String::new()];
+///
+/// clone_assign!(config.name = defaults.name, history[0] = defaults.name);
+/// assert_eq!(config.name, "default");
+/// assert_eq!(history, ["default"]);
+/// ```
+#[proc_macro]
+pub fn clone_assign(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_assign = syn::parse_macro_input!(input as clone_assign::CloneAssign);
+    proc_macro::TokenStream::from(clone_assign.to_token_stream())
+}
+
+/// Clones the elements of a slice into an existing slice of the same length
+///
+/// Overwrites every element of the destination in place with
+/// `clone_from_slice`, so an array or a `Vec` keeps its buffer instead of
+/// being replaced by a new one.
+///
+/// # Forms
+/// - `clone_slice!(dst, src)` -> `dst.clone_from_slice(&src)`
+/// - `clone_slice!(frame.pixels, row)` -> `frame.pixels.clone_from_slice(&row)`
+///
+/// The destination can be any mutable place that derefs to a slice, such as
+/// an array, a `Vec` or a field holding one. The source can be any
+/// expression that borrows as a slice, including a range like `src[..4]`.
+///
+/// # Panics
+/// Panics if the two lengths differ. The lengths of arrays are part of their
+/// types, but `clone_from_slice` compares them at run time.
+///
+/// # Example
+/// ```
+/// use letclone::clone_slice;
+///
+/// let source = [1, 2, 3, 4];
+/// let mut buffer = [0; 4];
+/// clone_slice!(buffer, source);
+/// assert_eq!(buffer, [1, 2, 3, 4]);
+/// ```
+#[proc_macro]
+pub fn clone_slice(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_slice = syn::parse_macro_input!(input as clone_slice::CloneSlice);
+    proc_macro::TokenStream::from(clone_slice.to_token_stream())
+}
+
+/// Pushes a clone onto a collection
+///
+/// Works with any collection that has a `push(value)` method, such as `Vec`
+/// or `String`.
+///
+/// # Forms
+/// - `clone_push!(items, item)` -> `items.push(item.clone())`
+/// - `clone_push!(report.lines, line.text)` -> `report.lines.push(line.text.clone())`
+///
+/// # Example
+/// ```
+/// use letclone::clone_push;
+///
+/// let template = String::from("row");
+/// let mut rows = Vec::new();
+/// for _ in 0..2 {
+///     clone_push!(rows, template);
+/// }
+/// assert_eq!(rows, ["row", "row"]);
+/// ```
+#[proc_macro]
+pub fn clone_push(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_push = syn::parse_macro_input!(input as clone_push::ClonePush);
+    proc_macro::TokenStream::from(clone_push.to_token_stream())
+}
+
+/// Inserts clones of a key and a value into a map
+///
+/// Works with any map that has an `insert(key, value)` method, such as
+/// `HashMap` or `BTreeMap`, and evaluates to what `insert` returns.
+///
+/// # Forms
+/// - `clone_insert!(map, key, value)` -> `map.insert(key.clone(), value.clone())`
+///
+/// # Example
+/// ```
+/// use letclone::clone_insert;
+/// use std::collections::HashMap;
+///
+/// let key = String::from("name");
+/// let value = String::from("Alice");
+/// let mut map = HashMap::new();
+///
+/// assert_eq!(clone_insert!(map, key, value), None);
+/// assert_eq!(map[&key], value);
+/// ```
+#[proc_macro]
+pub fn clone_insert(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_insert = syn::parse_macro_input!(input as clone_push::CloneInsert);
+    proc_macro::TokenStream::from(clone_insert.to_token_stream())
+}
+
+/// Clones a value and sends the clone over a channel
+///
+/// Works with any channel whose sender has a `send(value) -> Result<_, _>`
+/// method, such as `std::sync::mpsc::Sender`.
+///
+/// # Forms
+/// - `clone_send!(tx, state)` -> `tx.send(state.clone()).expect("...")`
+///
+///   Panics if the receiving end has been dropped.
+/// - `clone_send!(try tx, state)` -> `tx.send(state.clone())`
+///
+///   Returns the `Result` of `send`, so the error can be propagated with `?`
+///   or handled by the caller.
+///
+/// The source can be any expression, e.g. `clone_send!(tx, actor.state)`.
+///
+/// # Example
+/// ```
+/// use letclone::clone_send;
+/// use std::sync::mpsc;
+///
+/// struct Actor {
+///     name: String,
+/// }
+///
+/// let actor = Actor { name: String::from("worker") };
+/// let (tx, rx) = mpsc::channel();
+///
+/// clone_send!(tx, actor.name);
+/// assert_eq!(rx.recv().unwrap(), "worker");
+///
+/// drop(rx);
+/// assert!(clone_send!(try tx, actor.name).is_err());
+/// ```
+#[proc_macro]
+pub fn clone_send(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let clone_send = syn::parse_macro_input!(input as clone_send::CloneSend);
+    proc_macro::TokenStream::from(clone_send.to_token_stream())
+}
+
+/// Clones captures before the closure a function returns
+///
+/// The attribute takes the same entries as [`clone!`]. They are cloned at
+/// the start of the function, and the closure the body ends with is turned
+/// into a `move` closure so it owns the clones. This keeps builder-style
+/// methods returning `impl Fn()` free of clone boilerplate.
+///
+/// # Example
+/// ```
+/// use letclone::clone_captures;
+///
+/// struct Greeter {
+///     name: String,
+/// }
+///
+/// impl Greeter {
+///     #[clone_captures(self.name)]
+///     fn greeting(&self) -> impl Fn() -> String + use<> {
+///         || format!("Hello, {}!", name)
+///     }
+/// }
+///
+/// let greeter = Greeter { name: String::from("Alice") };
+/// let greet = greeter.greeting();
+/// drop(greeter);
+/// assert_eq!(greet(), "Hello, Alice!");
+/// ```
+/// expands the method body to:
+/// ```text
+/// let name = self.name.clone();
+/// move || format!("Hello, {}!", name)
+/// ```
+#[proc_macro_attribute]
+pub fn clone_captures(
+    attr: proc_macro::TokenStream,
+    item: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    let captures = syn::parse_macro_input!(attr as CloneExprList);
+    let item = syn::parse_macro_input!(item as syn::ItemFn);
+    match clone_captures::expand(captures, item) {
+        Ok(item) => proc_macro::TokenStream::from(item.to_token_stream()),
+        Err(e) => proc_macro::TokenStream::from(e.to_compile_error()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Expands a `clone!` input the same way the macro does
+    fn expand(input: proc_macro2::TokenStream) -> String {
+        syn::parse2::<CloneExprList>(input)
+            .unwrap()
+            .to_token_stream()
+            .to_string()
+    }
+
+    // The default forms must expand to exactly the hand-written statements,
+    // with no wrappers, so `clone!` stays free relative to manual code.
+
+    #[test]
+    fn path_expands_to_plain_clone() {
+        assert_eq!(
+            expand(quote! { a }),
+            quote! { let a = a.clone(); }.to_string()
+        );
+    }
+
+    #[test]
+    fn field_expands_to_plain_clone() {
+        assert_eq!(
+            expand(quote! { a.b.c }),
+            quote! { let c = a.b.c.clone(); }.to_string()
+        );
+    }
+
+    #[test]
+    fn tuple_index_expands_to_plain_clone() {
+        assert_eq!(
+            expand(quote! { a.0 }),
+            quote! { let field_0 = a.0.clone(); }.to_string()
+        );
+    }
+
+    #[test]
+    fn method_call_expands_to_plain_clone() {
+        assert_eq!(
+            expand(quote! { a.get(1) }),
+            quote! { let get = a.get(1).clone(); }.to_string()
+        );
+    }
+
+    #[test]
+    fn mut_expands_to_plain_clone() {
+        assert_eq!(
+            expand(quote! { mut a.b }),
+            quote! { let mut b = a.b.clone(); }.to_string()
+        );
+    }
+
+    #[test]
+    fn multiple_expand_to_one_statement_each() {
+        assert_eq!(
+            expand(quote! { a, mut b.c, d.e() }),
+            quote! {
+                let a = a.clone();
+                let mut c = b.c.clone();
+                let e = d.e().clone();
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn shared_computed_base_is_evaluated_once() {
+        assert_eq!(
+            expand(quote! { hoist; load().1 as name, load().3 as email, record.1 as other }),
+            quote! {
+                let (name, email) = {
+                    let __base0 = load();
+                    let name = __base0.1.clone();
+                    let email = __base0.3.clone();
+                    (name, email)
+                };
+                let other = record.1.clone();
+            }
+            .to_string()
+        );
+        // Equal calls are only shared when asked for
+        assert_eq!(
+            expand(quote! { load().1 as name, load().3 as email }),
+            quote! {
+                let name = load().1.clone();
+                let email = load().3.clone();
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn hoisted_group_keeps_entry_attributes() {
+        assert_eq!(
+            expand(quote! { #[cfg(any())] => hoist; load().a as a, mut load().b as b }),
+            quote! {
+                #[cfg(any())]
+                let (a, mut b) = {
+                    let __base0 = load();
+                    #[cfg(any())]
+                    let a = __base0.a.clone();
+                    #[cfg(any())]
+                    let b = __base0.b.clone();
+                    (a, b)
+                };
+            }
+            .to_string()
+        );
+        // Entries with different attributes evaluate the base themselves
+        assert_eq!(
+            expand(quote! { hoist; #[cfg(any())] load().a as a, load().b as b }),
+            quote! {
+                #[cfg(any())]
+                let a = load().a.clone();
+                let b = load().b.clone();
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "tracing")]
+    fn trace_logs_before_each_clone() {
+        assert_eq!(
+            expand(quote! { trace; a, b.c }),
+            quote! {
+                ::tracing::trace!("cloning {}", stringify!(a));
+                let a = a.clone();
+                ::tracing::trace!("cloning {}", stringify!(b.c));
+                let c = b.c.clone();
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "metrics")]
+    fn count_increments_counter_before_each_clone() {
+        assert_eq!(
+            expand(quote! { count = stats::CLONES; a, b.c }),
+            quote! {
+                stats::CLONES.fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);
+                let a = a.clone();
+                stats::CLONES.fetch_add(1, ::core::sync::atomic::Ordering::Relaxed);
+                let c = b.c.clone();
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn autoclone_skips_locals_callees_and_paths() {
+        let tokens = syn::parse2::<autoclone::AutoClone>(quote! {
+            |x| { let y = x + base; helper(y, cfg.limit, MAX, std::mem::size_of::<u8>()) }
+        })
+        .unwrap()
+        .to_token_stream()
+        .to_string();
+        assert_eq!(
+            tokens,
+            quote! {
+                {
+                    let base = base.clone();
+                    let cfg = cfg.clone();
+                    move |x| { let y = x + base; helper(y, cfg.limit, MAX, std::mem::size_of::<u8>()) }
+                }
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn or_else_replaces_error_propagation() {
+        let tokens = syn::parse2::<try_clone::TryClone>(quote! { file or_else { return } as f })
+            .unwrap()
+            .to_token_stream()
+            .to_string();
+        let value = Ident::new("__value", Span::mixed_site());
+        assert_eq!(
+            tokens,
+            quote! {
+                let f = match file.try_clone() {
+                    ::core::result::Result::Ok(#value) => #value,
+                    ::core::result::Result::Err(_) => { return },
+                };
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    fn gated_directives_expand_both_variants() {
+        assert_eq!(
+            expand(quote! { #[cfg(feature = "sync")] wrap = Arc; state as s }),
+            quote! {
+                #[cfg(feature = "sync")]
+                let s = Arc::new(state.clone());
+                #[cfg(not(feature = "sync"))]
+                let s = state.clone();
+            }
+            .to_string()
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "anyhow")]
+    fn context_wraps_fallible_clone() {
+        let tokens = syn::parse2::<try_clone::TryClone>(quote! { file context "cloning" as f })
+            .unwrap()
+            .to_token_stream()
+            .to_string();
+        assert_eq!(
+            tokens,
+            quote! {
+                let f = ::anyhow::Context::context(file.try_clone(), "cloning")?;
+            }
+            .to_string()
+        );
+    }
+}
@@ -0,0 +1,551 @@
+use proc_macro2::{Span, TokenStream};
+use quote::{quote, quote_spanned};
+use syn::ext::IdentExt;
+use syn::parse::{Parse, ParseStream};
+use syn::spanned::Spanned;
+use syn::{Expr, Ident, LitStr, Path, Token, Type};
+
+/// A keyword written before the source expression that changes how the
+/// bound value is produced, e.g. `clone!(into_owned cfg.path as path)`
+pub(crate) struct Modifier {
+    pub(crate) keyword: Ident,
+    kind: ModifierKind,
+}
+
+enum ModifierKind {
+    /// `into_owned`: appends `.into_owned()` to the clone, turning a `Cow`
+    /// into its owned form
+    IntoOwned,
+    /// `take`: moves the source into the binding instead of cloning it
+    Take,
+    /// `replace`: takes the source's value, leaving `Default::default()` in
+    /// its place, instead of cloning it
+    Replace,
+    /// `cast`: casts the clone to the annotated type with `as`
+    Cast,
+    /// `upcast`: coerces the clone of a smart pointer to a trait object into
+    /// the annotated pointer to a supertrait object
+    Upcast,
+    /// `deref`: clones the value behind the source's `Deref` impl
+    Deref,
+    /// `map_clone`: clones the value inside an `Option` without cloning the
+    /// `Option` itself, via `.as_ref().map(Clone::clone)`
+    MapClone,
+    /// `get` / `get("message")`: clones the value inside an initialized
+    /// `OnceLock`/`OnceCell`, via `.get().expect(message)`
+    Get(Option<LitStr>),
+    /// `collect`: clones the items of an iterator (or of anything iterable by
+    /// reference) into the annotated collection type
+    Collect,
+    /// `ref_pat`: binds the clone with a `ref` pattern, `let ref x = ...`
+    RefPat,
+    /// `view`: keeps the clone in a hidden local and binds a borrowed view of
+    /// it, e.g. a `&str` for a `String`
+    View,
+    /// `reserve(n)`: reserves additional capacity in the clone, which makes
+    /// the binding mutable
+    Reserve(Expr),
+    /// `sorted`: sorts the clone with `sort`, which makes the binding mutable
+    Sorted,
+    /// `sorted_by(compare)`: sorts the clone with `sort_by`, which makes the
+    /// binding mutable
+    SortedBy(Expr),
+    /// `retain(predicate)`: keeps the elements of the clone matching the
+    /// predicate with `retain`, which makes the binding mutable
+    Retain(Expr),
+    /// `dedup`: removes consecutive duplicates from the clone with `dedup`,
+    /// which makes the binding mutable
+    Dedup,
+    /// `reverse`: reverses the clone in place, which makes the binding
+    /// mutable
+    Reverse,
+    /// `shrink`: shrinks the capacity of the clone with `shrink_to_fit`, which
+    /// makes the binding mutable
+    Shrink,
+    /// `copy`: copies the source out of a reference instead of calling
+    /// `clone`, for `Copy` types such as `fn` pointers
+    Copy,
+    /// `flatten`: flattens the clone of a nested `Option`, turning
+    /// `Option<Option<T>>` into `Option<T>`
+    Flatten,
+    /// `ok`: turns the clone of a `Result` into an `Option` of its `Ok` value
+    Ok,
+    /// `err`: turns the clone of a `Result` into an `Option` of its `Err`
+    /// value
+    Err,
+    /// `q`: applies `?` to the clone of an `Option` or `Result`, returning
+    /// early on `None` or `Err`
+    Question,
+    /// `first`: clones the first item of an iterator source into an `Option`
+    First,
+    /// `cow_borrowed`: wraps the source reference in `Cow::Borrowed` instead
+    /// of cloning it
+    CowBorrowed,
+    /// `boxed_str`: converts the clone of a `String` into a `Box<str>`
+    BoxedStr,
+    /// `boxed_slice`: converts the clone of a `Vec<T>` into a `Box<[T]>`
+    BoxedSlice,
+    /// `cow_owned`: wraps the clone in `Cow::Owned`
+    CowOwned,
+    /// `wrap(Type)`: wraps the clone in `Type::new`, e.g. `Box`, `Arc` or
+    /// `Mutex`
+    Wrap(Path),
+    /// `drop`: drops the variable the source is read from once the clone is
+    /// made
+    Drop,
+    /// `lock`: clones the value inside a `Mutex`, releasing the guard right
+    /// after the clone
+    Lock,
+    /// `read`: clones the value inside an `RwLock` through a read guard,
+    /// releasing it right after the clone
+    Read,
+    /// `black_box`: passes the clone through `std::hint::black_box` so the
+    /// optimizer cannot elide it in benchmarks
+    BlackBox,
+    /// `getter`: calls the field of a field access source as a getter
+    /// method, `obj.name` -> `obj.name()`
+    Getter,
+    /// Used by `try_clone_from!`: converts the clone into the annotated type
+    /// with `TryFrom` and propagates the error with `?`
+    TryFrom,
+    /// `timed`: reports clones slower than [`TIMED_THRESHOLD_MS`] on stderr
+    /// when `debug_assertions` are enabled
+    Timed,
+    /// `stamped`: pairs the clone with the `Instant` it was made, for caches
+    Stamped,
+}
+
+/// Duration above which a `timed` clone is reported
+const TIMED_THRESHOLD_MS: u64 = 1;
+
+impl Modifier {
+    const KEYWORDS: &'static [&'static str] = &[
+        "into_owned",
+        "take",
+        "replace",
+        "cast",
+        "upcast",
+        "deref",
+        "map_clone",
+        "get",
+        "reserve",
+        "sorted",
+        "sorted_by",
+        "retain",
+        "dedup",
+        "reverse",
+        "shrink",
+        "collect",
+        "ref_pat",
+        "view",
+        "timed",
+        "stamped",
+        "copy",
+        "flatten",
+        "first",
+        "q",
+        "ok",
+        "err",
+        "cow_owned",
+        "cow_borrowed",
+        "boxed_str",
+        "boxed_slice",
+        "wrap",
+        "drop",
+        "lock",
+        "read",
+        "black_box",
+        "getter",
+    ];
+
+    /// Keywords that may be followed by a parenthesized argument
+    const WITH_ARGS: &'static [&'static str] = &["get", "reserve", "wrap", "sorted_by", "retain"];
+
+    /// Keywords that must be followed by a parenthesized argument
+    const REQUIRES_ARGS: &'static [&'static str] = &["reserve", "wrap", "sorted_by", "retain"];
+
+    /// Keywords after which `&`, `*` and `<` are binary operators, as in
+    /// `clone!(read & mask as bits)`: their sources are locks, which method
+    /// calls reach through references and smart pointers anyway
+    const OPERAND_NAMES: &'static [&'static str] = &["lock", "read"];
+
+    /// Returns `true` if the input starts with a modifier keyword followed by
+    /// the start of a source expression.
+    ///
+    /// A bare `clone!(into_owned)` or `clone!(into_owned.field)` still clones
+    /// a variable named `into_owned`.
+    pub(crate) fn peek(input: ParseStream) -> bool {
+        let fork = input.fork();
+        let Ok(ident) = fork.call(Ident::parse_any) else {
+            return false;
+        };
+        if !Self::KEYWORDS.iter().any(|keyword| ident == keyword) {
+            return false;
+        }
+        if Self::REQUIRES_ARGS.iter().any(|keyword| ident == keyword)
+            && !fork.peek(syn::token::Paren)
+        {
+            return false;
+        }
+        if Self::WITH_ARGS.iter().any(|keyword| ident == keyword)
+            && fork.peek(syn::token::Paren)
+            && fork.parse::<proc_macro2::Group>().is_err()
+        {
+            return false;
+        }
+        if Self::OPERAND_NAMES.iter().any(|keyword| ident == keyword)
+            && (fork.peek(Token![&]) || fork.peek(Token![*]) || fork.peek(Token![<]))
+        {
+            return false;
+        }
+        starts_source(&fork)
+    }
+
+    /// Creates a `wrap(Type)` modifier, used by the `wrap = Type` directive
+    pub(crate) fn wrap(keyword: Ident, wrapper: Path) -> Self {
+        Modifier {
+            keyword,
+            kind: ModifierKind::Wrap(wrapper),
+        }
+    }
+
+    /// Creates a `lock` modifier, used by `clone_guard!` for entries that do
+    /// not choose a guard themselves
+    pub(crate) fn lock(keyword: Ident) -> Self {
+        Modifier {
+            keyword,
+            kind: ModifierKind::Lock,
+        }
+    }
+
+    /// Creates the conversion used by `try_clone_from!`, which has no keyword
+    /// of its own
+    pub(crate) fn try_from(keyword: Ident) -> Self {
+        Modifier {
+            keyword,
+            kind: ModifierKind::TryFrom,
+        }
+    }
+
+    /// Returns `true` if the modifier clones the value behind a lock guard
+    pub(crate) fn acquires_guard(&self) -> bool {
+        matches!(self.kind, ModifierKind::Lock | ModifierKind::Read)
+    }
+
+    /// Returns `true` if the modifier produces the value from the source
+    /// itself instead of starting from `source.clone()`.
+    pub(crate) fn replaces_clone(&self) -> bool {
+        matches!(
+            self.kind,
+            ModifierKind::Take
+                | ModifierKind::Replace
+                | ModifierKind::MapClone
+                | ModifierKind::Collect
+                | ModifierKind::Copy
+                | ModifierKind::First
+                | ModifierKind::Lock
+                | ModifierKind::Read
+                | ModifierKind::CowBorrowed
+        )
+    }
+
+    /// Returns `true` if the modifier needs a `: Type` annotation on the entry
+    pub(crate) fn requires_type(&self) -> bool {
+        matches!(
+            self.kind,
+            ModifierKind::Cast | ModifierKind::Collect | ModifierKind::Upcast
+        )
+    }
+
+    /// Returns `true` if the modifier changes the shape of the value enough
+    /// that the source name would be misleading, so a rename is required
+    pub(crate) fn requires_rename(&self) -> bool {
+        matches!(
+            self.kind,
+            ModifierKind::Flatten
+                | ModifierKind::First
+                | ModifierKind::Ok
+                | ModifierKind::Err
+                | ModifierKind::Stamped
+        )
+    }
+
+    /// A type to suggest when the annotation required by the modifier is
+    /// missing
+    pub(crate) fn example_type(&self) -> &'static str {
+        match self.kind {
+            ModifierKind::Collect => "Vec<_>",
+            ModifierKind::Upcast => "Arc<dyn Trait>",
+            _ => "u64",
+        }
+    }
+
+    /// Returns `true` if the modifier mutates the binding after it is created
+    pub(crate) fn implies_mut(&self) -> bool {
+        matches!(
+            self.kind,
+            ModifierKind::Reserve(_)
+                | ModifierKind::Sorted
+                | ModifierKind::SortedBy(_)
+                | ModifierKind::Retain(_)
+                | ModifierKind::Dedup
+                | ModifierKind::Reverse
+                | ModifierKind::Shrink
+        )
+    }
+
+    /// Returns `true` if the modifier binds the value with a `ref` pattern
+    pub(crate) fn binds_by_ref(&self) -> bool {
+        matches!(self.kind, ModifierKind::RefPat)
+    }
+
+    /// Returns `true` if the binding borrows a view of a clone kept in hidden
+    /// storage
+    pub(crate) fn binds_view(&self) -> bool {
+        matches!(self.kind, ModifierKind::View)
+    }
+
+    /// Transforms the expression the clone method is called on
+    pub(crate) fn receiver(&self, receiver: TokenStream) -> TokenStream {
+        match &self.kind {
+            ModifierKind::Deref => quote! { (*#receiver) },
+            ModifierKind::Getter => quote! { #receiver() },
+            ModifierKind::Get(message) => {
+                let message = match message {
+                    Some(message) => quote! { #message },
+                    None => quote! { concat!("`", stringify!(#receiver), "` is not initialized") },
+                };
+                quote! { #receiver.get().expect(#message) }
+            }
+            _ => receiver,
+        }
+    }
+
+    /// Produces the initial value from the receiver for modifiers that
+    /// replace the clone
+    pub(crate) fn source(&self, source: &TokenStream) -> Option<TokenStream> {
+        match &self.kind {
+            ModifierKind::Take => Some(quote! { #source }),
+            ModifierKind::Replace => {
+                // Spanned at the source so a missing `mut` is reported there
+                let place = quote_spanned! {source.span()=> &mut #source };
+                Some(quote! {
+                    ::core::mem::replace(#place, ::core::default::Default::default())
+                })
+            }
+            ModifierKind::MapClone => Some(quote! {
+                #source.as_ref().map(::core::clone::Clone::clone)
+            }),
+            ModifierKind::Copy => Some(quote! { *&#source }),
+            ModifierKind::First => Some(quote! { #source.next().cloned() }),
+            ModifierKind::CowBorrowed => Some(quote! { ::std::borrow::Cow::Borrowed(#source) }),
+            ModifierKind::Lock | ModifierKind::Read => {
+                let method = if matches!(self.kind, ModifierKind::Lock) {
+                    quote! { lock }
+                } else {
+                    quote! { read }
+                };
+                let guard = Ident::new("__guard", Span::mixed_site());
+                // The guard is a block local, so the lock is released as soon
+                // as the clone is made rather than at the end of the statement
+                Some(quote! {
+                    {
+                        let #guard = #source.#method().expect(concat!("`", stringify!(#source), "` is poisoned"));
+                        ::core::clone::Clone::clone(&*#guard)
+                    }
+                })
+            }
+            ModifierKind::Collect => Some(quote! {
+                ::core::iter::IntoIterator::into_iter(#source).cloned().collect()
+            }),
+            _ => None,
+        }
+    }
+
+    /// Wraps the tokens producing the bound value
+    pub(crate) fn apply(&self, value: TokenStream, ty: Option<&Type>) -> TokenStream {
+        match &self.kind {
+            ModifierKind::IntoOwned => quote! { #value.into_owned() },
+            ModifierKind::Flatten => quote! { #value.flatten() },
+            ModifierKind::Question => quote! { #value? },
+            ModifierKind::Ok => quote! { #value.ok() },
+            ModifierKind::Err => quote! { #value.err() },
+            ModifierKind::CowOwned => quote! { ::std::borrow::Cow::Owned(#value) },
+            ModifierKind::BoxedStr => quote! { #value.into_boxed_str() },
+            ModifierKind::BoxedSlice => quote! { #value.into_boxed_slice() },
+            ModifierKind::Wrap(wrapper) => quote! { #wrapper::new(#value) },
+            ModifierKind::BlackBox => quote! { ::core::hint::black_box(#value) },
+            ModifierKind::Stamped => quote! { (#value, ::std::time::Instant::now()) },
+            ModifierKind::Cast => quote! { #value as #ty },
+            ModifierKind::TryFrom => quote! {
+                <#ty as ::core::convert::TryFrom<_>>::try_from(#value)?
+            },
+            ModifierKind::Timed => {
+                let start = Ident::new("__start", Span::mixed_site());
+                let result = Ident::new("__value", Span::mixed_site());
+                quote! {
+                    {
+                        #[cfg(debug_assertions)]
+                        let #start = ::std::time::Instant::now();
+                        let #result = #value;
+                        #[cfg(debug_assertions)]
+                        {
+                            let elapsed = #start.elapsed();
+                            if elapsed > ::core::time::Duration::from_millis(#TIMED_THRESHOLD_MS) {
+                                ::std::eprintln!("clone! `{}` took {:?}", stringify!(#value), elapsed);
+                            }
+                        }
+                        #result
+                    }
+                }
+            }
+            _ => value,
+        }
+    }
+
+    /// Checks that the modifier can be applied to the source
+    pub(crate) fn validate(&self, source: &Expr) -> syn::Result<()> {
+        match &self.kind {
+            ModifierKind::Drop if local_root(source).is_none() => Err(syn::Error::new(
+                self.keyword.span(),
+                "`drop` requires a source read from a local variable, such as `big` or `big.data`",
+            )),
+            ModifierKind::Getter if !is_named_field(source) => Err(syn::Error::new(
+                self.keyword.span(),
+                "`getter` requires a named field source, such as `obj.name`",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Statements run on the binding right after it is created, in the order
+    /// the modifiers are written
+    pub(crate) fn post(&self, binding: &Ident, source: &Expr) -> Option<TokenStream> {
+        match &self.kind {
+            ModifierKind::Drop => {
+                let root = local_root(source)?;
+                Some(quote! { ::core::mem::drop(#root); })
+            }
+            ModifierKind::Reserve(additional) => Some(quote! { #binding.reserve(#additional); }),
+            ModifierKind::Sorted => Some(quote! { #binding.sort(); }),
+            ModifierKind::SortedBy(compare) => Some(quote! { #binding.sort_by(#compare); }),
+            ModifierKind::Retain(predicate) => Some(quote! { #binding.retain(#predicate); }),
+            ModifierKind::Dedup => Some(quote! { #binding.dedup(); }),
+            ModifierKind::Reverse => Some(quote! { #binding.reverse(); }),
+            ModifierKind::Shrink => Some(quote! { #binding.shrink_to_fit(); }),
+            _ => None,
+        }
+    }
+}
+
+impl Parse for Modifier {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword = input.call(Ident::parse_any)?;
+        let kind = match keyword.to_string().as_str() {
+            "into_owned" => ModifierKind::IntoOwned,
+            "take" => ModifierKind::Take,
+            "replace" => ModifierKind::Replace,
+            "cast" => ModifierKind::Cast,
+            "upcast" => ModifierKind::Upcast,
+            "deref" => ModifierKind::Deref,
+            "map_clone" => ModifierKind::MapClone,
+            "collect" => ModifierKind::Collect,
+            "ref_pat" => ModifierKind::RefPat,
+            "view" => ModifierKind::View,
+            "timed" => ModifierKind::Timed,
+            "stamped" => ModifierKind::Stamped,
+            "copy" => ModifierKind::Copy,
+            "flatten" => ModifierKind::Flatten,
+            "first" => ModifierKind::First,
+            "q" => ModifierKind::Question,
+            "ok" => ModifierKind::Ok,
+            "err" => ModifierKind::Err,
+            "cow_owned" => ModifierKind::CowOwned,
+            "cow_borrowed" => ModifierKind::CowBorrowed,
+            "boxed_str" => ModifierKind::BoxedStr,
+            "boxed_slice" => ModifierKind::BoxedSlice,
+            "drop" => ModifierKind::Drop,
+            "lock" => ModifierKind::Lock,
+            "read" => ModifierKind::Read,
+            "black_box" => ModifierKind::BlackBox,
+            "getter" => ModifierKind::Getter,
+            "wrap" => {
+                let content;
+                syn::parenthesized!(content in input);
+                ModifierKind::Wrap(content.call(Path::parse_mod_style)?)
+            }
+            "get" if input.peek(syn::token::Paren) => {
+                let content;
+                syn::parenthesized!(content in input);
+                ModifierKind::Get(Some(content.parse()?))
+            }
+            "get" => ModifierKind::Get(None),
+            "sorted" => ModifierKind::Sorted,
+            "dedup" => ModifierKind::Dedup,
+            "reverse" => ModifierKind::Reverse,
+            "shrink" => ModifierKind::Shrink,
+            "retain" => {
+                let content;
+                syn::parenthesized!(content in input);
+                ModifierKind::Retain(content.parse()?)
+            }
+            "sorted_by" => {
+                let content;
+                syn::parenthesized!(content in input);
+                ModifierKind::SortedBy(content.parse()?)
+            }
+            "reserve" => {
+                let content;
+                syn::parenthesized!(content in input);
+                ModifierKind::Reserve(content.parse()?)
+            }
+            _ => return Err(syn::Error::new(keyword.span(), "unknown clone! modifier")),
+        };
+        Ok(Modifier { keyword, kind })
+    }
+}
+
+/// Returns the local variable a place expression is read from, e.g. `big` in
+/// `big.data.items`
+fn local_root(expr: &Expr) -> Option<&Ident> {
+    match expr {
+        Expr::Field(field) => local_root(&field.base),
+        Expr::Group(group) => local_root(&group.expr),
+        Expr::Path(path) if path.qself.is_none() => {
+            path.path.get_ident().filter(|ident| *ident != "self")
+        }
+        _ => None,
+    }
+}
+
+/// Returns `true` if the expression accesses a named field, e.g. `obj.name`
+fn is_named_field(expr: &Expr) -> bool {
+    match expr {
+        Expr::Field(field) => matches!(field.member, syn::Member::Named(_)),
+        Expr::Group(group) => is_named_field(&group.expr),
+        _ => false,
+    }
+}
+
+/// Words that follow a source instead of starting one, so that
+/// `clone!(first where ..)` clones a variable named `first`
+const CLAUSE_WORDS: &[&str] = &["where", "via", "context", "or_else", "as"];
+
+/// Returns `true` if the input can begin a source expression
+fn starts_source(input: ParseStream) -> bool {
+    if let Some((ident, _)) = input.cursor().ident()
+        && CLAUSE_WORDS.iter().any(|word| ident == word)
+    {
+        return false;
+    }
+    input.peek(Ident)
+        || input.peek(Token![self])
+        || input.peek(Token![Self])
+        || input.peek(Token![crate])
+        || input.peek(Token![super])
+        || input.peek(syn::Lit)
+        || input.peek(Token![&])
+        || input.peek(Token![*])
+        || input.peek(Token![::])
+        || input.peek(Token![<])
+}
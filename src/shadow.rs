@@ -0,0 +1,103 @@
+use quote::{ToTokens, quote};
+use syn::visit::{self, Visit};
+use syn::{Attribute, Expr, Ident, LitStr};
+
+use crate::{CloneExpr, deprecation_warning, unwrap_group};
+
+/// A source that reads a name bound by an earlier entry of the same
+/// invocation, such as `config` in `clone!(config.name as config, config.port)`,
+/// reported with a warning before the entry that reads it
+pub(crate) struct Shadowed {
+    /// Index of the entry reading the shadowed name
+    pub(crate) index: usize,
+    attrs: Vec<Attribute>,
+    ident: Ident,
+}
+
+impl ToTokens for Shadowed {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        let Shadowed { attrs, ident, .. } = self;
+        let message = LitStr::new(
+            &format!(
+                "`{ident}` refers to the clone bound by an earlier entry of this macro, not the original `{ident}`"
+            ),
+            ident.span(),
+        );
+        let warning = deprecation_warning("shadowed_binding", &message, ident.span());
+        tokens.extend(quote! { #(#attrs)* #warning });
+    }
+}
+
+/// Finds sources that read a binding which replaced a variable of the same
+/// name earlier in the invocation.
+///
+/// Entries expand to consecutive `let` statements, so later entries see the
+/// bindings of earlier ones. That is the point when the name is new, as in
+/// `clone!(config.name, name.len() as len)`, but when the binding replaces an
+/// existing variable the later source silently clones the clone instead of
+/// the original. Without name resolution, a variable is known to exist only
+/// if a source up to the rebinding entry reads it. Entries that rebind a
+/// variable to a plain clone of itself, as in `clone!(x, x as y)`, are not
+/// reported since reading the clone is equivalent.
+pub(crate) fn shadowed(exprs: &[CloneExpr]) -> Vec<Shadowed> {
+    let names: Vec<Vec<Ident>> = exprs
+        .iter()
+        .map(|expr| {
+            let mut finder = NameFinder::default();
+            finder.visit_expr(&expr.inner);
+            finder.names
+        })
+        .collect();
+    let mut shadowed = Vec::new();
+    for (index, expr) in exprs.iter().enumerate() {
+        for ident in &names[index] {
+            let replaced = exprs[..index]
+                .iter()
+                .enumerate()
+                .any(|(earlier, rebinding)| {
+                    rebinding.binding() == *ident
+                        && !is_plain_rebinding(rebinding)
+                        && names[..=earlier].iter().flatten().any(|read| read == ident)
+                });
+            if replaced {
+                shadowed.push(Shadowed {
+                    index,
+                    attrs: expr.attrs.clone(),
+                    ident: ident.clone(),
+                });
+            }
+        }
+    }
+    shadowed
+}
+
+/// Returns `true` if the entry binds a clone of a variable to the variable's
+/// own name without changing the value, e.g. `clone!(x)`
+fn is_plain_rebinding(expr: &CloneExpr) -> bool {
+    let plain = expr.modifiers.is_empty()
+        && expr.by_ref.is_none()
+        && expr.ty.is_none()
+        && expr.via.is_none()
+        && expr.using.is_none();
+    plain
+        && matches!(unwrap_group(&expr.inner), Expr::Path(path)
+            if path.qself.is_none() && path.path.get_ident() == Some(&expr.binding()))
+}
+
+/// Collects the single-segment paths a source reads, in order of first use
+#[derive(Default)]
+struct NameFinder {
+    names: Vec<Ident>,
+}
+
+impl<'ast> Visit<'ast> for NameFinder {
+    fn visit_expr_path(&mut self, path: &'ast syn::ExprPath) {
+        if path.qself.is_none()
+            && let Some(ident) = path.path.get_ident()
+            && !self.names.contains(ident)
+        {
+            self.names.push(ident.clone());
+        }
+        visit::visit_expr_path(self, path);
+    }
+}
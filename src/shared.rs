@@ -0,0 +1,60 @@
+use quote::ToTokens;
+use syn::Ident;
+use syn::parse::{Parse, ParseStream};
+
+use crate::CloneExprList;
+use crate::modifier::Modifier;
+
+/// Input of the `shared!` macro: regular clone entries whose clones are put
+/// in a new `Rc<RefCell<_>>`
+pub(crate) struct Shared {
+    exprs: CloneExprList,
+}
+
+impl Parse for Shared {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword = Ident::new("wrap", input.span());
+        let mut exprs: CloneExprList = input.parse()?;
+        for expr in exprs.entries_mut() {
+            expr.modifiers.push(Modifier::wrap(
+                keyword.clone(),
+                syn::parse_quote!(::core::cell::RefCell),
+            ));
+            expr.modifiers.push(Modifier::wrap(
+                keyword.clone(),
+                syn::parse_quote!(::std::rc::Rc),
+            ));
+        }
+        Ok(Shared { exprs })
+    }
+}
+
+impl ToTokens for Shared {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.exprs.to_tokens(tokens);
+    }
+}
+
+/// Input of the `share_clone!` macro: regular clone entries whose sources are
+/// `Rc` handles, cloned with `Rc::clone` so the binding shares the value
+pub(crate) struct ShareClone {
+    exprs: CloneExprList,
+}
+
+impl Parse for ShareClone {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut exprs: CloneExprList = input.parse()?;
+        for expr in exprs.entries_mut() {
+            if expr.using.is_none() {
+                expr.using = Some(syn::parse_quote!(::std::rc::Rc::clone));
+            }
+        }
+        Ok(ShareClone { exprs })
+    }
+}
+
+impl ToTokens for ShareClone {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.exprs.to_tokens(tokens);
+    }
+}
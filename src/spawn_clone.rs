@@ -0,0 +1,58 @@
+use proc_macro2::{Spacing, TokenTree};
+use quote::ToTokens;
+use syn::parse::{Parse, ParseStream, Parser};
+use syn::{Block, Token};
+
+use crate::CloneExprList;
+
+/// Input of the `spawn_clone!` macro: regular clone entries followed by
+/// `=> { body }`, run on a new thread by a `move` closure capturing the clones
+pub(crate) struct SpawnClone {
+    exprs: CloneExprList,
+}
+
+impl Parse for SpawnClone {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let tokens: Vec<TokenTree> = input
+            .parse::<proc_macro2::TokenStream>()?
+            .into_iter()
+            .collect();
+        // The last top-level `=>` separates the entries from the body; an
+        // earlier one may belong to `#[...] =>` attributes
+        let arrow = tokens.windows(2).rposition(|pair| {
+            matches!(pair, [TokenTree::Punct(eq), TokenTree::Punct(gt)]
+                if eq.as_char() == '=' && eq.spacing() == Spacing::Joint && gt.as_char() == '>')
+        });
+        let Some(arrow) = arrow else {
+            return Err(syn::Error::new(
+                input.span(),
+                "spawn_clone! expects the clones followed by the thread body, e.g. `spawn_clone!(a, b => { ... })`",
+            ));
+        };
+        let entries = tokens[..arrow].iter().cloned().collect();
+        let body: proc_macro2::TokenStream = tokens[arrow + 2..].iter().cloned().collect();
+        let block = Parser::parse2(
+            |input: ParseStream| {
+                let block: Block = input.parse()?;
+                let _trailing: Option<Token![,]> = input.parse()?;
+                Ok(block)
+            },
+            body,
+        )
+        .map_err(|e| {
+            syn::Error::new(
+                e.span(),
+                "expected a block after `=>`, e.g. `spawn_clone!(a, b => { ... })`",
+            )
+        })?;
+        let mut exprs: CloneExprList = syn::parse2(entries)?;
+        exprs.body = Some(syn::parse_quote! { ::std::thread::spawn(move || #block) });
+        Ok(SpawnClone { exprs })
+    }
+}
+
+impl ToTokens for SpawnClone {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.exprs.to_tokens(tokens);
+    }
+}
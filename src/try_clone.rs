@@ -0,0 +1,30 @@
+use quote::ToTokens;
+use syn::Ident;
+use syn::parse::{Parse, ParseStream};
+
+use crate::CloneExprList;
+
+/// Input of the `try_clone!` macro: regular clone entries whose clone method
+/// is the fallible `try_clone`
+pub(crate) struct TryClone {
+    exprs: CloneExprList,
+}
+
+impl Parse for TryClone {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let method = Ident::new("try_clone", input.span());
+        let mut exprs: CloneExprList = input.parse()?;
+        for expr in exprs.entries_mut() {
+            expr.clone_method = method.clone();
+            expr.fallible = true;
+            expr.check_clone_method("try_clone")?;
+        }
+        Ok(TryClone { exprs })
+    }
+}
+
+impl ToTokens for TryClone {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.exprs.to_tokens(tokens);
+    }
+}
@@ -0,0 +1,36 @@
+use quote::ToTokens;
+use syn::Ident;
+use syn::parse::{Parse, ParseStream};
+
+use crate::CloneExprList;
+use crate::modifier::Modifier;
+
+/// Input of the `try_clone_from!` macro: regular clone entries, each with a
+/// type annotation, whose clones are converted with `TryFrom`
+pub(crate) struct TryCloneFrom {
+    exprs: CloneExprList,
+}
+
+impl Parse for TryCloneFrom {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let keyword = Ident::new("try_from", input.span());
+        let mut exprs: CloneExprList = input.parse()?;
+        for expr in exprs.entries_mut() {
+            if expr.ty.is_none() {
+                return Err(syn::Error::new_spanned(
+                    &expr.inner,
+                    "try_clone_from! requires a target type annotation, e.g. `try_clone_from!(raw: Parsed)`",
+                ));
+            }
+            expr.modifiers
+                .insert(0, Modifier::try_from(keyword.clone()));
+        }
+        Ok(TryCloneFrom { exprs })
+    }
+}
+
+impl ToTokens for TryCloneFrom {
+    fn to_tokens(&self, tokens: &mut proc_macro2::TokenStream) {
+        self.exprs.to_tokens(tokens);
+    }
+}
@@ -5,4 +5,17 @@ fn ui_tests() {
     t.pass("tests/ui/pass_*.rs");
     // Failing tests
     t.compile_fail("tests/ui/fail_*.rs");
+    // Tests depending on which crate features are enabled
+    #[cfg(feature = "tracing")]
+    t.pass("tests/ui/features/pass_trace.rs");
+    #[cfg(not(feature = "tracing"))]
+    t.compile_fail("tests/ui/features/fail_trace_without_feature.rs");
+    #[cfg(feature = "anyhow")]
+    t.pass("tests/ui/features/pass_context.rs");
+    #[cfg(not(feature = "anyhow"))]
+    t.compile_fail("tests/ui/features/fail_context_without_feature.rs");
+    #[cfg(feature = "metrics")]
+    t.pass("tests/ui/features/pass_count.rs");
+    #[cfg(not(feature = "metrics"))]
+    t.compile_fail("tests/ui/features/fail_count_without_feature.rs");
 }
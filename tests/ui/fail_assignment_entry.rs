@@ -0,0 +1,7 @@
+// Test: an assignment is not a clone entry
+use letclone::clone;
+
+fn main() {
+    let a = String::from("a");
+    clone!(b = a.clone(), a);
+}
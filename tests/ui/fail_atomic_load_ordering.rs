@@ -0,0 +1,8 @@
+// Test: atomic_load! rejects orderings that are invalid for loads
+use letclone::atomic_load;
+use std::sync::atomic::AtomicUsize;
+
+fn main() {
+    let counter = AtomicUsize::new(0);
+    atomic_load!(Release; counter as n);
+}
@@ -0,0 +1,14 @@
+// Test: atomic_load! rejects entries that would skip the load
+use letclone::atomic_load;
+use std::sync::atomic::AtomicUsize;
+
+fn snapshot(counter: &AtomicUsize) -> AtomicUsize {
+    AtomicUsize::new(counter.load(std::sync::atomic::Ordering::Relaxed))
+}
+
+fn main() {
+    let mut counter = AtomicUsize::new(0);
+    atomic_load!(take counter as n);
+    atomic_load!(counter via into_inner as m);
+    atomic_load!(using snapshot; counter as k);
+}
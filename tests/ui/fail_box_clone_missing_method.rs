@@ -0,0 +1,19 @@
+// Test: box_clone! fails when the trait has no box_clone method
+use letclone::box_clone;
+
+trait Widget {
+    fn label(&self) -> String;
+}
+
+struct Button;
+
+impl Widget for Button {
+    fn label(&self) -> String {
+        String::from("ok")
+    }
+}
+
+fn main() {
+    let widget: Box<dyn Widget> = Box::new(Button);
+    box_clone!(widget);
+}
@@ -0,0 +1,21 @@
+// Test: box_clone! rejects entries that would skip the configured method
+use letclone::box_clone;
+
+trait Widget {
+    fn duplicate(&self) -> Box<dyn Widget>;
+}
+
+struct Button;
+
+impl Widget for Button {
+    fn duplicate(&self) -> Box<dyn Widget> {
+        Box::new(Button)
+    }
+}
+
+fn main() {
+    let mut widget: Option<Box<dyn Widget>> = Some(Box::new(Button));
+    box_clone!(via duplicate; take widget as taken);
+    let other: Box<dyn Widget> = Box::new(Button);
+    box_clone!(other via clone as copy);
+}
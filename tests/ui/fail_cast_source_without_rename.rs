@@ -0,0 +1,7 @@
+// Test: `as u64` is a cast, not a rename to `u64`
+use letclone::clone;
+
+fn main() {
+    let x = 1u32;
+    clone!(x as u64);
+}
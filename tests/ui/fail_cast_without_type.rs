@@ -0,0 +1,7 @@
+// Test: the cast modifier requires a target type annotation
+use letclone::clone;
+
+fn main() {
+    let count = 1u32;
+    clone!(cast count as total);
+}
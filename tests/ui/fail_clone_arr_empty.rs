@@ -0,0 +1,6 @@
+// Test: clone_arr! requires at least one expression
+use letclone::clone_arr;
+
+fn main() {
+    let _: [String; 0] = clone_arr!();
+}
@@ -0,0 +1,7 @@
+// Test: clone_assign! requires `<place> = <source>`
+use letclone::clone_assign;
+
+fn main() {
+    let source = String::from("value");
+    clone_assign!(source);
+}
@@ -0,0 +1,7 @@
+// Test: only a closure or an async block can follow `=>`
+use letclone::clone;
+
+fn main() {
+    let state = String::new();
+    let _ = clone!(state => state.len());
+}
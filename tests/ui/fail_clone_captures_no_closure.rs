@@ -0,0 +1,15 @@
+// Test: #[clone_captures] requires the body to end with a closure
+use letclone::clone_captures;
+
+struct Service {
+    db: String,
+}
+
+impl Service {
+    #[clone_captures(self.db)]
+    fn name(&self) -> String {
+        String::from("name")
+    }
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+// Test: excluded fields must exist
+use letclone::clone_fields;
+
+struct Person {
+    id: u64,
+    name: String,
+}
+
+fn main() {
+    let person = Person {
+        id: 1,
+        name: String::from("Ann"),
+    };
+    let _ = person.id;
+    clone_fields!(person => name, !identifier);
+}
@@ -0,0 +1,11 @@
+// Test: `clone_fields!` cannot enumerate fields
+use letclone::clone_fields;
+
+struct Person {
+    id: u64,
+}
+
+fn main() {
+    let person = Person { id: 1 };
+    clone_fields!(person => *, !id);
+}
@@ -0,0 +1,10 @@
+use letclone::clone_guard;
+use std::sync::Mutex;
+
+fn main() {
+    let mut slot = Mutex::new(Some(1));
+    clone_guard!(take slot as value);
+
+    let queue = Mutex::new(vec![1, 2]);
+    clone_guard!(queue via to_owned as jobs);
+}
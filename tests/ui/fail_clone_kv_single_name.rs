@@ -0,0 +1,9 @@
+// Test: clone_kv! requires exactly two binding names
+use letclone::clone_kv;
+use std::collections::HashMap;
+
+fn main() {
+    let map: HashMap<String, String> = HashMap::new();
+    let entry = map.iter().next().unwrap();
+    clone_kv!(entry as (k,));
+}
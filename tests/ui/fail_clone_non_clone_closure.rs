@@ -0,0 +1,12 @@
+// Test: a closure capturing a non-`Clone` value cannot be cloned
+use letclone::clone;
+
+struct Connection;
+
+fn main() {
+    let connection = Connection;
+    let callback = move || {
+        let _ = &connection;
+    };
+    clone!(callback);
+}
@@ -0,0 +1,7 @@
+// Test: `clone_slice!` requires a destination and a source
+use letclone::clone_slice;
+
+fn main() {
+    let mut target = [0; 4];
+    clone_slice!(target);
+}
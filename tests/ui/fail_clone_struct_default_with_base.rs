@@ -0,0 +1,14 @@
+// Test: `..default` cannot be combined with an explicit `..base`
+use letclone::clone_struct;
+
+#[derive(Default)]
+struct Request {
+    url: String,
+    retries: u32,
+}
+
+fn main() {
+    let template = Request::default();
+    let base = Request::default();
+    let _ = clone_struct!(Request { url, ..base } from template, ..default);
+}
@@ -0,0 +1,22 @@
+// Test: cloning a trait object fails at the source; `using` or `box_clone!`
+// clone it instead
+use letclone::clone;
+
+trait Shape {
+    fn area(&self) -> f64;
+}
+
+struct Square(f64);
+
+impl Shape for Square {
+    fn area(&self) -> f64 {
+        self.0 * self.0
+    }
+}
+
+fn main() {
+    let shape: Box<dyn Shape> = Box::new(Square(2.0));
+    clone!(shape as copy);
+    let borrowed: &dyn Shape = &Square(1.0);
+    clone!(*borrowed as owned);
+}
@@ -0,0 +1,9 @@
+// Test: naming more fields than the tuple has is a compile error
+use letclone::clone_tuple;
+
+struct Point(f64, f64);
+
+fn main() {
+    let point = Point(1.0, 2.0);
+    clone_tuple!(point => x, y, z);
+}
@@ -0,0 +1,6 @@
+// Test: cloned! requires at least one expression
+use letclone::cloned;
+
+fn main() {
+    let _: String = cloned!();
+}
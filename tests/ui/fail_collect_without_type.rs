@@ -0,0 +1,7 @@
+// Test: `collect` requires a type annotation
+use letclone::clone;
+
+fn main() {
+    let items = vec![1, 2, 3];
+    clone!(collect items.iter() as owned);
+}
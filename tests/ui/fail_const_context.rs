@@ -0,0 +1,18 @@
+// Test: `clone!` calls `Clone::clone`, which cannot be called in const contexts
+use letclone::clone;
+
+#[derive(Clone)]
+struct Limits {
+    max: u32,
+}
+
+const DEFAULT: Limits = Limits { max: 8 };
+
+const COPY: Limits = {
+    clone!(DEFAULT as limits);
+    limits
+};
+
+fn main() {
+    let _ = COPY.max;
+}
@@ -0,0 +1,8 @@
+// Test: `copy` requires a `Copy` source
+use letclone::clone;
+
+fn main() {
+    let name = String::from("Ada");
+    let greet = move || name.len();
+    clone!(copy greet as copied);
+}
@@ -0,0 +1,7 @@
+// Test: `cow_borrowed` does not clone, so `via` has nothing to replace
+use letclone::clone;
+
+fn main() {
+    let s = "text";
+    clone!(cow_borrowed s via to_owned);
+}
@@ -0,0 +1,14 @@
+// Test: `drop` requires a source read from a local variable
+use letclone::clone;
+
+struct Big {
+    data: Vec<u8>,
+}
+
+fn load() -> Big {
+    Big { data: vec![1] }
+}
+
+fn main() {
+    clone!(drop load().data as data);
+}
@@ -0,0 +1,16 @@
+// Test: the dropped variable cannot be used after the clone
+use letclone::clone;
+
+struct Big {
+    data: Vec<u8>,
+    name: String,
+}
+
+fn main() {
+    let big = Big {
+        data: vec![1],
+        name: String::from("big"),
+    };
+    clone!(drop big.data as data);
+    println!("{:?} {}", data, big.name);
+}
@@ -0,0 +1,9 @@
+// Test: an attribute on one entry does not apply to the others
+#![deny(unused_variables)]
+use letclone::clone;
+
+fn main() {
+    let temp = String::from("temp");
+    let real = String::from("real");
+    clone!(#[allow(unused_variables)] temp, real);
+}
@@ -0,0 +1,8 @@
+// Test: a `#[cfg_attr]` whose condition does not hold has no effect
+#![deny(unused_variables)]
+use letclone::clone;
+
+fn main() {
+    let data = String::from("data");
+    clone!(#[cfg_attr(test, allow(unused_variables))] data as unused);
+}
@@ -0,0 +1,7 @@
+// Test: `first` requires a rename
+use letclone::clone;
+
+fn main() {
+    let items = vec![1, 2, 3];
+    clone!(first items);
+}
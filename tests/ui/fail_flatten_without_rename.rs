@@ -0,0 +1,7 @@
+// Test: `flatten` requires a rename
+use letclone::clone;
+
+fn main() {
+    let nested = Some(Some(1));
+    clone!(flatten nested);
+}
@@ -0,0 +1,7 @@
+// Test: only `#[cfg(...)]` can gate a directive block
+use letclone::clone;
+
+fn main() {
+    let state = String::new();
+    clone!(#[allow(unused)] mut; state);
+}
@@ -0,0 +1,8 @@
+// Test: a directive cannot be set by both a gated and the ungated block
+use letclone::clone;
+
+fn main() {
+    let a = String::from("a");
+    clone!(#[cfg(all())] suffix_index; suffix_index; a);
+    clone!(#[cfg(all())] using String::clone; intern = String::clone; a);
+}
@@ -0,0 +1,11 @@
+// Test: `deref` on a generic source needs the `Clone` bound
+use letclone::clone;
+
+fn owned<T>(items: &Vec<T>) -> Vec<T> {
+    clone!(deref items as owned);
+    owned
+}
+
+fn main() {
+    let _ = owned(&vec![1]);
+}
@@ -0,0 +1,7 @@
+// Test: `getter` requires a named field source
+use letclone::clone;
+
+fn main() {
+    let pair = (String::from("a"), 1);
+    clone!(getter pair.0 as first);
+}
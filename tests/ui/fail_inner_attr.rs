@@ -0,0 +1,7 @@
+// Test: inner attributes are rejected on clone entries
+use letclone::clone;
+
+fn main() {
+    let temp = String::from("temp");
+    clone!(#![allow(unused)] temp);
+}
@@ -0,0 +1,12 @@
+// Test: `intern` and `using` cannot be combined
+use letclone::clone;
+use std::sync::Arc;
+
+fn intern(value: &String) -> Arc<str> {
+    Arc::from(value.as_str())
+}
+
+fn main() {
+    let name = String::from("ann");
+    clone!(using String::clone intern = intern; name as copy);
+}
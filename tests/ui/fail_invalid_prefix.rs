@@ -0,0 +1,7 @@
+// Test: clone! rejects a prefix that produces an invalid identifier
+use letclone::clone;
+
+fn main() {
+    let a = String::from("a");
+    clone!(prefix = "1st-"; a);
+}
@@ -0,0 +1,7 @@
+// Test: a string rename must be a valid identifier
+use letclone::clone;
+
+fn main() {
+    let value = String::from("hello");
+    clone!(value as "custom name");
+}
@@ -0,0 +1,21 @@
+// Test: `self`, `Self`, `super` and `crate` cannot name a binding
+use letclone::clone;
+
+#[derive(Clone)]
+struct Unit;
+
+impl Unit {
+    fn copy(&self) {
+        clone!(self);
+    }
+
+    fn unit() {
+        clone!(Self);
+    }
+}
+
+fn main() {
+    let value = String::from("value");
+    clone!(value as self);
+    clone!(prefix = "sel"; value as f);
+}
@@ -0,0 +1,6 @@
+// Test: a macro source needs a rename
+use letclone::clone;
+
+fn main() {
+    clone!(vec![1, 2, 3]);
+}
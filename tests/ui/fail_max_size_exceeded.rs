@@ -0,0 +1,13 @@
+// Test: `max_size = N` rejects clones larger than `N` bytes
+use letclone::clone;
+
+#[derive(Clone)]
+struct Large {
+    buffer: [u8; 64],
+}
+
+fn main() {
+    let large = Large { buffer: [0; 64] };
+    clone!(max_size = 16; large);
+    let _ = large.buffer;
+}
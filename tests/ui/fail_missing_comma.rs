@@ -0,0 +1,8 @@
+// Test: clone! with a missing comma between entries should fail
+use letclone::clone;
+
+fn main() {
+    let a = String::from("a");
+    let b = String::from("b");
+    clone!(a b);
+}
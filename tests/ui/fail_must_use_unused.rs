@@ -0,0 +1,11 @@
+// Test: `must_use` reports unused clones even where the lint is allowed
+#![deny(warnings)]
+#![allow(unused_variables)]
+use letclone::clone;
+
+fn main() {
+    let expensive = vec![0u8; 1024];
+    let cheap = 1;
+    clone!(must_use; expensive, cheap);
+    let _ = cheap;
+}
@@ -0,0 +1,17 @@
+// Test: a missing `Clone` impl is reported at the entry, not the whole macro
+use letclone::clone;
+
+struct Connection;
+
+struct Pool {
+    connection: Connection,
+    name: String,
+}
+
+fn main() {
+    let pool = Pool {
+        connection: Connection,
+        name: String::from("main"),
+    };
+    clone!(pool.name, pool.connection as connection);
+}
@@ -0,0 +1,7 @@
+// Test: `ok` requires a rename
+use letclone::clone;
+
+fn main() {
+    let result: Result<u32, ()> = Ok(1);
+    clone!(ok result);
+}
@@ -0,0 +1,7 @@
+// Test: `or_else` is only supported by `try_clone!`
+use letclone::clone;
+
+fn main() {
+    let name = String::from("name");
+    clone!(name or_else { return } as copy);
+}
@@ -0,0 +1,8 @@
+// Test: parentheses do not give `a + b` a name
+use letclone::clone;
+
+fn main() {
+    let a = 1;
+    let b = 2;
+    clone!((a + b));
+}
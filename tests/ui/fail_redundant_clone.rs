@@ -0,0 +1,20 @@
+// Test: a source already ending with `.clone()` or `.to_owned()` is reported
+#![deny(deprecated)]
+use letclone::clone;
+
+struct Config {
+    name: String,
+}
+
+fn main() {
+    let x = String::from("x");
+    let config = Config {
+        name: String::from("main"),
+    };
+    clone!(x.clone() as y, config.name.to_owned() as name);
+    drop((y, name));
+
+    // Cloning through another method is not reported
+    clone!(x.clone() via into_boxed_str as boxed);
+    drop(boxed);
+}
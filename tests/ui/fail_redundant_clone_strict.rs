@@ -0,0 +1,7 @@
+// Test: the `strict` directive makes a redundant clone an error
+use letclone::clone;
+
+fn main() {
+    let x = String::from("x");
+    clone!(strict; x.to_owned() as y);
+}
@@ -0,0 +1,7 @@
+// Test: clone! rejects `ref` without `mut`
+use letclone::clone;
+
+fn main() {
+    let buf = String::from("hello");
+    clone!(ref buf);
+}
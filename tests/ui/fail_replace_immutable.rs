@@ -0,0 +1,12 @@
+// Test: `replace` needs mutable access to the source
+use letclone::clone;
+
+struct Slot {
+    value: Vec<u32>,
+}
+
+fn main() {
+    let slot = Slot { value: vec![1] };
+    clone!(replace slot.value as old);
+    assert_eq!(old, [1]);
+}
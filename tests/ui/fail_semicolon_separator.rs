@@ -0,0 +1,8 @@
+// Test: clone! rejects a semicolon between entries instead of ignoring the rest
+use letclone::clone;
+
+fn main() {
+    let a = String::from("a");
+    let b = String::from("b");
+    clone!(a; b);
+}
@@ -0,0 +1,8 @@
+// Test: `send` directive rejects clones that are not `Send`
+use letclone::clone;
+use std::rc::Rc;
+
+fn main() {
+    let state = Rc::new(String::from("shared"));
+    clone!(send; state);
+}
@@ -0,0 +1,15 @@
+// Test: a source reading a name bound by an earlier entry is reported
+#![deny(deprecated)]
+use letclone::clone;
+
+struct Config {
+    name: String,
+}
+
+fn main() {
+    let name = Config { name: String::from("app") };
+    let label = String::from("label");
+    clone!(name.name as name, label as name_copy, name as copy);
+    assert_eq!(copy, name);
+    assert_eq!(name_copy, label);
+}
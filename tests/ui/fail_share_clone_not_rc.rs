@@ -0,0 +1,7 @@
+// Test: `share_clone!` only accepts `Rc` handles
+use letclone::share_clone;
+
+fn main() {
+    let values = vec![1, 2, 3];
+    share_clone!(values as other);
+}
@@ -0,0 +1,9 @@
+// Test: `spawn_clone!` requires a block body after `=>`
+use letclone::spawn_clone;
+use std::sync::Arc;
+
+fn main() {
+    let state = Arc::new(1);
+    let _ = spawn_clone!(state);
+    let _ = spawn_clone!(state => || println!("{state}"));
+}
@@ -0,0 +1,7 @@
+// Test: `stamped` requires a rename
+use letclone::clone;
+
+fn main() {
+    let data = vec![1u32];
+    clone!(stamped data);
+}
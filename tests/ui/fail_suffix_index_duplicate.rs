@@ -0,0 +1,7 @@
+// Test: `suffix_index` can only be given once
+use letclone::clone;
+
+fn main() {
+    let a = 1;
+    clone!(suffix_index suffix_index; a);
+}
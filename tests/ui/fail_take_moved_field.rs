@@ -0,0 +1,15 @@
+// Test: a field moved out by the take modifier cannot be used again
+use letclone::clone;
+
+struct Data {
+    name: String,
+}
+
+fn main() {
+    let data = Data {
+        name: String::from("report"),
+    };
+    clone!(take data.name as name);
+    assert_eq!(name, "report");
+    assert_eq!(data.name, "report");
+}
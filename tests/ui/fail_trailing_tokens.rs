@@ -0,0 +1,15 @@
+// Test: clone! rejects trailing tokens after the last entry
+use letclone::clone;
+
+struct Data {
+    x: String,
+    y: String,
+}
+
+fn main() {
+    let data = Data {
+        x: String::from("x"),
+        y: String::from("y"),
+    };
+    clone!(data.x, data.y data);
+}
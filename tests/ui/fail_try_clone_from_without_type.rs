@@ -0,0 +1,10 @@
+use letclone::try_clone_from;
+
+fn parse(raw: &String) -> Result<u32, std::num::ParseIntError> {
+    try_clone_from!(raw);
+    Ok(0)
+}
+
+fn main() {
+    let _ = parse(&String::new());
+}
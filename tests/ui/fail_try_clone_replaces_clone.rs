@@ -0,0 +1,12 @@
+// Test: try_clone! rejects entries that would skip `try_clone`
+use letclone::try_clone;
+use std::fs::File;
+
+fn duplicate(file: &mut Option<File>, port: u16) -> std::io::Result<()> {
+    try_clone!(take file as taken);
+    try_clone!(copy port as copied);
+    try_clone!(port via to_owned as owned);
+    Ok(())
+}
+
+fn main() {}
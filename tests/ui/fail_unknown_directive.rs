@@ -0,0 +1,7 @@
+// Test: clone! rejects unknown directives
+use letclone::clone;
+
+fn main() {
+    let a = String::from("a");
+    clone!(mut suffix = "_copy"; a);
+}
@@ -0,0 +1,10 @@
+// Test: `unsafe deref` is reported by the `unsafe_code` lint at the user's `unsafe`
+#![deny(unsafe_code)]
+use letclone::clone;
+
+fn main() {
+    let text = String::from("text");
+    let ptr: *const String = &text;
+    clone!(unsafe deref ptr as copy);
+    assert_eq!(copy, "text");
+}
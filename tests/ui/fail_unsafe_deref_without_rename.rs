@@ -0,0 +1,8 @@
+// Test: `unsafe deref` requires a rename
+use letclone::clone;
+
+fn main() {
+    let owned = String::from("value");
+    let ptr: *const String = &owned;
+    clone!(unsafe deref ptr);
+}
@@ -0,0 +1,12 @@
+// Test: `upcast` requires a target type annotation
+use letclone::clone;
+use std::sync::Arc;
+
+trait Base {}
+
+fn main() {
+    let handle: Arc<dyn Base> = Arc::new(());
+    clone!(upcast handle as base);
+}
+
+impl Base for () {}
@@ -0,0 +1,7 @@
+// Test: `view` cannot be combined with `ref mut`
+use letclone::clone;
+
+fn main() {
+    let text = String::from("text");
+    clone!(ref mut view text as t);
+}
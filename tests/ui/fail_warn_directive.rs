@@ -0,0 +1,9 @@
+// Test: `warn("...")` reports the message as a deprecation warning
+#![deny(deprecated)]
+use letclone::clone;
+
+fn main() {
+    let big = vec![0u8; 1 << 16];
+    clone!(warn("use Arc instead"); big as copy);
+    assert_eq!(copy.len(), big.len());
+}
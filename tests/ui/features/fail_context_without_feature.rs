@@ -0,0 +1,9 @@
+// Test: the `context` clause requires the `anyhow` feature
+use letclone::try_clone;
+
+fn duplicate(file: &std::fs::File) -> std::io::Result<std::fs::File> {
+    try_clone!(file context "cloning log file" as f);
+    Ok(f)
+}
+
+fn main() {}
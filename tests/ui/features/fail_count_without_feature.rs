@@ -0,0 +1,10 @@
+// Test: the `count` directive requires the `metrics` feature
+use letclone::clone;
+use std::sync::atomic::AtomicUsize;
+
+static LETCLONE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+fn main() {
+    let state = String::from("state");
+    clone!(count; state);
+}
@@ -0,0 +1,7 @@
+// Test: the `trace` directive requires the `tracing` feature
+use letclone::clone;
+
+fn main() {
+    let state = String::from("state");
+    clone!(trace; state);
+}
@@ -0,0 +1,31 @@
+// Test: the `context` clause attaches a message to a failed `try_clone`
+use letclone::try_clone;
+use std::io;
+
+struct Handle {
+    broken: bool,
+}
+
+impl Handle {
+    fn try_clone(&self) -> io::Result<Handle> {
+        if self.broken {
+            Err(io::Error::other("handle closed"))
+        } else {
+            Ok(Handle { broken: false })
+        }
+    }
+}
+
+fn duplicate(handle: &Handle) -> anyhow::Result<Handle> {
+    try_clone!(handle context "cloning the handle" as copy);
+    Ok(copy)
+}
+
+fn main() {
+    assert!(duplicate(&Handle { broken: false }).is_ok());
+
+    let error = duplicate(&Handle { broken: true }).err().unwrap();
+    assert_eq!(error.to_string(), "cloning the handle");
+    let chain: Vec<String> = error.chain().map(ToString::to_string).collect();
+    assert_eq!(chain, ["cloning the handle", "handle closed"]);
+}
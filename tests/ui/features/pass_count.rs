@@ -0,0 +1,32 @@
+// Test: the `count` directive increments a counter for every clone
+use letclone::clone;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static LETCLONE_COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+mod stats {
+    use std::sync::atomic::AtomicUsize;
+
+    pub static CLONES: AtomicUsize = AtomicUsize::new(0);
+}
+
+fn main() {
+    let a = String::from("a");
+    let b = vec![1, 2, 3];
+
+    clone!(count; a, b);
+    assert_eq!(LETCLONE_COUNTER.load(Ordering::Relaxed), 2);
+    assert_eq!((a.as_str(), b.len()), ("a", 3));
+
+    for _ in 0..3 {
+        clone!(count mut; a as copy);
+        copy.push('!');
+    }
+    assert_eq!(LETCLONE_COUNTER.load(Ordering::Relaxed), 5);
+
+    // A counter can be named explicitly
+    clone!(count = stats::CLONES; a as other);
+    assert_eq!(other, "a");
+    assert_eq!(stats::CLONES.load(Ordering::Relaxed), 1);
+    assert_eq!(LETCLONE_COUNTER.load(Ordering::Relaxed), 5);
+}
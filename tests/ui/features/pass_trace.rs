@@ -0,0 +1,14 @@
+// Test: the `trace` directive logs every clone through `tracing`
+use letclone::clone;
+
+fn main() {
+    let a = String::from("a");
+    let b = vec![1, 2, 3];
+
+    clone!(trace; a, b);
+    assert_eq!((a.as_str(), b.len()), ("a", 3));
+
+    clone!(trace mut; a as copy);
+    copy.push('!');
+    assert_eq!(copy, "a!");
+}
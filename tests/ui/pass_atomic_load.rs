@@ -0,0 +1,41 @@
+// Test: atomic_load! snapshotting atomics with `load`
+use letclone::atomic_load;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+struct Stats {
+    hits: AtomicUsize,
+    ready: AtomicBool,
+}
+
+fn main() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    counter.fetch_add(3, Ordering::Relaxed);
+
+    // Equivalent to: let n = counter.load(Ordering::Relaxed);
+    atomic_load!(counter as n);
+    assert_eq!(n, 3);
+
+    // The snapshot does not follow later updates
+    counter.fetch_add(1, Ordering::Relaxed);
+    assert_eq!(n, 3);
+
+    // Explicit orderings, field access and derived names
+    let stats = Stats {
+        hits: AtomicUsize::new(7),
+        ready: AtomicBool::new(true),
+    };
+    atomic_load!(Acquire; stats.hits, stats.ready);
+    assert_eq!(hits, 7);
+    assert!(ready);
+
+    atomic_load!(SeqCst; mut counter as total);
+    total += 1;
+    assert_eq!(total, 5);
+
+    // Directives follow the ordering, or take its place
+    atomic_load!(send; stats.hits as sent);
+    assert_eq!(sent, 7);
+    atomic_load!(Acquire; suffix_index; stats.ready);
+    assert!(ready_0);
+}
@@ -0,0 +1,50 @@
+// Test: `autoclone!` clones the variables a closure captures
+use letclone::autoclone;
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+struct Config {
+    name: String,
+    retries: u32,
+}
+
+fn describe(name: &str, retries: u32) -> String {
+    format!("{}x{}", name, retries)
+}
+
+fn main() {
+    let config = Arc::new(Config {
+        name: String::from("job"),
+        retries: 3,
+    });
+    let results = Arc::new(Mutex::new(Vec::new()));
+
+    // Both captures are cloned, so the originals stay usable after the
+    // closure moves to another thread
+    let worker = thread::spawn(autoclone!(move || {
+        let line = describe(&config.name, config.retries);
+        results.lock().unwrap().push(line);
+    }));
+    worker.join().unwrap();
+    assert_eq!(*results.lock().unwrap(), ["jobx3"]);
+    assert_eq!(Arc::strong_count(&config), 1);
+
+    // Parameters and names bound inside the closure are not cloned, and
+    // macro arguments are inspected
+    let prefix = String::from("item");
+    let format = autoclone!(|index: usize| {
+        let suffix = index * 2;
+        match Some(suffix) {
+            Some(value) => format!("{}-{}", prefix, value),
+            None => prefix.clone(),
+        }
+    });
+    assert_eq!(format(2), "item-4");
+    assert_eq!(prefix, "item");
+
+    // A non-`move` closure becomes `move`
+    let counter = Arc::new(Mutex::new(0));
+    let increment = autoclone!(|| *counter.lock().unwrap() += 1);
+    drop(counter);
+    increment();
+}
@@ -0,0 +1,26 @@
+// Test: `black_box` modifier for benchmark clones
+use letclone::clone;
+
+struct Fixture {
+    data: Vec<u64>,
+}
+
+fn main() {
+    let data = vec![1u64, 2, 3];
+    let fixture = Fixture { data: vec![4, 5] };
+
+    // Equivalent to: let data = std::hint::black_box(data.clone());
+    clone!(black_box data);
+    assert_eq!(data, [1, 2, 3]);
+
+    for _ in 0..3 {
+        clone!(black_box fixture.data as sample, mut black_box fixture.data);
+        data.push(sample.len() as u64);
+        assert_eq!(data, [4, 5, 2]);
+    }
+
+    // Composes with other modifiers
+    let nested = Some(Some(7u8));
+    clone!(black_box flatten nested as value);
+    assert_eq!(value, Some(7));
+}
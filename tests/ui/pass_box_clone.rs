@@ -0,0 +1,48 @@
+// Test: box_clone! on dyn-clone-style trait objects
+use letclone::box_clone;
+
+trait Widget {
+    fn label(&self) -> String;
+    fn box_clone(&self) -> Box<dyn Widget>;
+    fn duplicate(&self) -> Box<dyn Widget>;
+}
+
+#[derive(Clone)]
+struct Button {
+    text: String,
+}
+
+impl Widget for Button {
+    fn label(&self) -> String {
+        self.text.clone()
+    }
+    fn box_clone(&self) -> Box<dyn Widget> {
+        Box::new(self.clone())
+    }
+    fn duplicate(&self) -> Box<dyn Widget> {
+        Box::new(Button {
+            text: format!("{} (copy)", self.text),
+        })
+    }
+}
+
+struct Window {
+    widget: Box<dyn Widget>,
+}
+
+fn main() {
+    let widget: Box<dyn Widget> = Box::new(Button {
+        text: String::from("ok"),
+    });
+    box_clone!(widget);
+    // Equivalent to: let widget = widget.box_clone();
+    assert_eq!(widget.label(), "ok");
+
+    let window = Window { widget };
+    box_clone!(window.widget as copy);
+    assert_eq!(copy.label(), "ok");
+
+    box_clone!(via duplicate; window.widget, window.widget as other);
+    assert_eq!(widget.label(), "ok (copy)");
+    assert_eq!(other.label(), "ok (copy)");
+}
@@ -0,0 +1,30 @@
+// Test: `boxed_str` and `boxed_slice` convert clones into boxed slices
+use letclone::clone;
+
+struct Symbol {
+    name: String,
+    bytes: Vec<u8>,
+}
+
+fn main() {
+    let s = Symbol {
+        name: String::from("intern"),
+        bytes: vec![1, 2, 3],
+    };
+
+    // Equivalent to: let name = s.name.clone().into_boxed_str();
+    clone!(boxed_str s.name as name, boxed_slice s.bytes as bytes);
+    let name: Box<str> = name;
+    let bytes: Box<[u8]> = bytes;
+    assert_eq!(&*name, "intern");
+    assert_eq!(&*bytes, &[1, 2, 3]);
+    assert_eq!(s.name, "intern");
+    assert_eq!(s.bytes, [1, 2, 3]);
+
+    // The boxes hold exactly the cloned data
+    let mut buffer = Vec::with_capacity(64);
+    buffer.extend_from_slice(b"abc");
+    clone!(boxed_slice buffer);
+    assert_eq!(buffer.len(), 3);
+    assert_eq!(buffer.into_vec().capacity(), 3);
+}
@@ -0,0 +1,27 @@
+// Test: clone! with the cast modifier
+use letclone::clone;
+
+struct Metrics {
+    count: u32,
+    ratio: f32,
+}
+
+fn main() {
+    let metrics = Metrics {
+        count: 42,
+        ratio: 0.5,
+    };
+
+    clone!(cast metrics.count as count: u64, cast metrics.ratio: f64);
+    // Equivalent to:
+    // let count: u64 = metrics.count.clone() as u64;
+    // let ratio: f64 = metrics.ratio.clone() as f64;
+    let count: u64 = count;
+    let ratio: f64 = ratio;
+    assert_eq!(count, 42u64);
+    assert_eq!(ratio, 0.5f64);
+
+    clone!(mut cast metrics.count as total: u64);
+    total += u64::from(u32::MAX);
+    assert_eq!(total, 4_294_967_337);
+}
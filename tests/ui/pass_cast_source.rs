@@ -0,0 +1,21 @@
+// Test: `as <name>` renames, `as <primitive>` casts the source
+use letclone::clone;
+
+fn main() {
+    let x = 300u32;
+
+    // A bare name is a rename
+    clone!(x as y);
+    assert_eq!(y, 300u32);
+
+    // A primitive type is a cast, which then needs a rename
+    clone!(x as u64 as wide, x as u8 as truncated, x as f64 as float);
+    let wide: u64 = wide;
+    assert_eq!(wide, 300);
+    assert_eq!(truncated, 44u8);
+    assert_eq!(float, 300.0);
+
+    // A string rename can still use a primitive type's name
+    clone!(x as "u64");
+    assert_eq!(u64, 300u32);
+}
@@ -0,0 +1,64 @@
+// Test: `#[cfg(...)] directives;` applies the directives only under the predicate
+use letclone::clone;
+use std::cell::Cell;
+use std::sync::Arc;
+
+thread_local! {
+    static CALLS: Cell<u32> = const { Cell::new(0) };
+}
+
+fn counted_clone(state: &State) -> State {
+    CALLS.set(CALLS.get() + 1);
+    state.clone()
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct State {
+    count: u32,
+}
+
+fn main() {
+    let state = State { count: 1 };
+
+    // `all()` is always true: the clone is wrapped in an `Arc`
+    clone!(#[cfg(all())] wrap = Arc; state as shared);
+    let shared: Arc<State> = shared;
+    assert_eq!(shared.count, 1);
+
+    // `any()` is always false: the clone is plain
+    clone!(#[cfg(any())] wrap = Arc; state as plain);
+    let plain: State = plain;
+    assert_eq!(plain, state);
+
+    // The gated block combines with an unconditional one, and several
+    // `cfg` attributes must all hold
+    clone!(#[cfg(all())] #[cfg(debug_assertions)] wrap = Arc; mut; state as counted);
+    #[cfg(debug_assertions)]
+    {
+        counted = Arc::new(State { count: 5 });
+    }
+    assert_eq!(counted.count, if cfg!(debug_assertions) { 5 } else { 1 });
+
+    // Hoisted sources are still evaluated once
+    let mut calls = 0;
+    let mut load = || {
+        calls += 1;
+        (State { count: 2 }, State { count: 3 })
+    };
+    clone!(#[cfg(any())] wrap = Arc; hoist; load().0 as first, load().1 as second);
+    assert_eq!((first.count, second.count), (2, 3));
+    assert_eq!(calls, 1);
+
+    // Directives of the gated block survive the ungated block
+    clone!(#[cfg(all())] using counted_clone; mut; state as via_fn);
+    via_fn.count += 1;
+    assert_eq!((via_fn.count, CALLS.get()), (2, 1));
+    clone!(#[cfg(any())] using counted_clone; mut; state as direct);
+    direct.count += 1;
+    assert_eq!((direct.count, CALLS.get()), (2, 1));
+    #[allow(deprecated)]
+    {
+        clone!(#[cfg(all())] warn("gated"); suffix_index; state);
+        assert_eq!(state_0, state);
+    }
+}
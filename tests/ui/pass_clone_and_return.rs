@@ -0,0 +1,69 @@
+// Test: a clone can be returned as the trailing expression of a block or fn
+use letclone::{clone, clone_guard, try_clone};
+use std::sync::Mutex;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Config {
+    name: String,
+    items: Vec<u32>,
+}
+
+fn snapshot(cfg: &Config) -> Config {
+    clone!(cfg);
+    cfg
+}
+
+fn name_of(cfg: &Config) -> String {
+    clone!(cfg.name);
+    name
+}
+
+fn with_capacity(cfg: &Config) -> Vec<u32> {
+    // Post-clone statements do not swallow the trailing expression
+    clone!(reserve(8) cfg.items);
+    items
+}
+
+fn edited(cfg: &Config) -> usize {
+    clone!(ref mut cfg.items as items);
+    items.push(0);
+    items.len()
+}
+
+fn locked(state: &Mutex<Config>) -> Config {
+    clone_guard!(state);
+    state
+}
+
+fn fallible(cfg: &Config) -> Result<Config, ()> {
+    struct Handle(Config);
+    impl Handle {
+        fn try_clone(&self) -> Result<Config, ()> {
+            Ok(self.0.clone())
+        }
+    }
+    let handle = Handle(cfg.clone());
+    try_clone!(handle as copy);
+    Ok(copy)
+}
+
+fn main() {
+    let cfg = Config {
+        name: String::from("app"),
+        items: vec![1, 2],
+    };
+
+    assert_eq!(snapshot(&cfg), cfg);
+    assert_eq!(name_of(&cfg), "app");
+    assert!(with_capacity(&cfg).capacity() >= 10);
+    assert_eq!(edited(&cfg), 3);
+    assert_eq!(locked(&Mutex::new(cfg.clone())), cfg);
+    assert_eq!(fallible(&cfg), Ok(cfg.clone()));
+
+    // Blocks and several entries
+    let (name, count) = {
+        clone!(cfg.name, cfg.items where |i| !i.is_empty());
+        (name, items.len())
+    };
+    assert_eq!((name.as_str(), count), ("app", 2));
+}
@@ -0,0 +1,38 @@
+// Test: clone_arr! building fixed-size arrays of clones
+use letclone::clone_arr;
+
+struct Names {
+    first: String,
+    middle: String,
+    last: String,
+}
+
+fn join(parts: [String; 3]) -> String {
+    parts.join(" ")
+}
+
+fn main() {
+    let names = Names {
+        first: String::from("Ada"),
+        middle: String::from("King"),
+        last: String::from("Lovelace"),
+    };
+
+    // Equivalent to: [names.first.clone(), names.middle.clone(), names.last.clone()]
+    let parts: [String; 3] = clone_arr!(names.first, names.middle, names.last);
+    assert_eq!(parts, ["Ada", "King", "Lovelace"]);
+
+    // Passed directly to a function expecting an array
+    assert_eq!(join(clone_arr!(names.last, names.first, names.middle)), "Lovelace Ada King");
+
+    // A single entry still produces a one-element array
+    let single: [String; 1] = clone_arr!(names.first);
+    assert_eq!(single, ["Ada"]);
+
+    // Any expression, with a trailing comma
+    let list = vec![1, 2, 3];
+    let mixed = clone_arr!(list[0], list[1] + list[2], list.len() as i32,);
+    assert_eq!(mixed, [1, 5, 3]);
+
+    assert_eq!(names.first, "Ada");
+}
@@ -0,0 +1,35 @@
+// Test: clone_assign! assigning clones into existing places
+use letclone::clone_assign;
+
+struct Profile {
+    name: String,
+    tags: Vec<String>,
+}
+
+fn main() {
+    let defaults = Profile {
+        name: String::from("guest"),
+        tags: vec![String::from("new")],
+    };
+    let mut profile = Profile {
+        name: String::new(),
+        tags: Vec::new(),
+    };
+
+    // Equivalent to: profile.name = defaults.name.clone();
+    clone_assign!(profile.name = defaults.name);
+    assert_eq!(profile.name, "guest");
+
+    // Several assignments, including a `Vec` element and a variable
+    let mut slots = vec![String::new(), String::new()];
+    let mut last;
+    clone_assign!(slots[1] = defaults.tags[0], profile.tags = defaults.tags, last = defaults.name,);
+    assert_eq!(slots, ["", "new"]);
+    assert_eq!(profile.tags, ["new"]);
+    assert_eq!(last, "guest");
+
+    // Any expression can be the source
+    clone_assign!(last = profile.name.to_uppercase() + "!");
+    assert_eq!(last, "GUEST!");
+    assert_eq!(defaults.name, "guest");
+}
@@ -0,0 +1,43 @@
+// Test: #[clone_captures] on methods returning closures
+use letclone::clone_captures;
+use std::sync::{Arc, Mutex};
+
+struct Service {
+    state: Arc<Mutex<Vec<String>>>,
+    db: String,
+}
+
+impl Service {
+    #[clone_captures(self.state, self.db)]
+    fn logger(&self) -> impl Fn(&str) + use<> {
+        |message| state.lock().unwrap().push(format!("{}: {}", db, message))
+    }
+
+    // Closures that are already `move` and renamed entries work as well
+    #[clone_captures(self.db as name)]
+    fn describe(&self, suffix: &'static str) -> impl Fn() -> String + use<> {
+        move || format!("{}{}", name, suffix)
+    }
+}
+
+fn main() {
+    let service = Service {
+        state: Arc::new(Mutex::new(Vec::new())),
+        db: String::from("primary"),
+    };
+
+    let log = service.logger();
+    let describe = service.describe("!");
+    let state = Arc::clone(&service.state);
+    drop(service);
+
+    // Both closures remain callable after the method returned and the
+    // service was dropped
+    log("started");
+    log("stopped");
+    assert_eq!(
+        *state.lock().unwrap(),
+        ["primary: started", "primary: stopped"]
+    );
+    assert_eq!(describe(), "primary!");
+}
@@ -0,0 +1,41 @@
+// Test: `clone_fields!` clones listed fields and skips excluded ones
+use letclone::clone_fields;
+use std::cell::Cell;
+
+#[derive(Clone)]
+struct Person {
+    id: u64,
+    name: String,
+    email: String,
+    internal: Vec<u8>,
+}
+
+fn main() {
+    let person = Person {
+        id: 7,
+        name: String::from("Ann"),
+        email: String::from("ann@example.com"),
+        internal: vec![1, 2, 3],
+    };
+
+    // Equivalent to: let name = person.name.clone(); let email = person.email.clone();
+    clone_fields!(person => name, mut email, !id, !internal);
+    email.push_str(".org");
+    assert_eq!(name, "Ann");
+    assert_eq!(email, "ann@example.com.org");
+    // `id` and `internal` were not bound: these refer to new locals
+    let id = "not cloned";
+    let internal = ();
+    assert_eq!((id, internal), ("not cloned", ()));
+    assert_eq!(person.internal.len(), 3);
+
+    // Excluded fields of a computed source are not evaluated separately
+    let calls = Cell::new(0);
+    let load = || {
+        calls.set(calls.get() + 1);
+        person.clone()
+    };
+    clone_fields!(load() => id, name, !email, !internal,);
+    assert_eq!((id, name.as_str()), (7, "Ann"));
+    assert_eq!(calls.get(), 1);
+}
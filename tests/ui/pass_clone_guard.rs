@@ -0,0 +1,45 @@
+// Test: `clone_guard!` clones out of locks and releases the guards
+use letclone::{clone, clone_guard};
+use std::sync::{Arc, Mutex, RwLock};
+
+struct Shared {
+    queue: Mutex<Vec<String>>,
+    config: RwLock<String>,
+}
+
+fn main() {
+    let mutex = Mutex::new(vec![1, 2, 3]);
+
+    // Equivalent to: let data = { let guard = mutex.lock().unwrap(); guard.clone() };
+    clone_guard!(mutex as data);
+    let data: Vec<i32> = data;
+    // The guard is released: locking again does not deadlock
+    mutex.lock().unwrap().push(4);
+    assert_eq!(data, [1, 2, 3]);
+    assert!(mutex.try_lock().is_ok());
+
+    let shared = Arc::new(Shared {
+        queue: Mutex::new(vec![String::from("job")]),
+        config: RwLock::new(String::from("debug")),
+    });
+    clone_guard!(mut shared.queue as jobs, read shared.config as level);
+    jobs.push(String::from("local"));
+    // Writing requires that no read guard is alive
+    *shared.config.try_write().unwrap() = String::from("release");
+    assert_eq!(jobs, ["job", "local"]);
+    assert_eq!(level, "debug");
+    assert_eq!(shared.queue.lock().unwrap().len(), 1);
+
+    // The data is owned and outlives the lock
+    let owned = {
+        let lock = RwLock::new(String::from("temporary"));
+        clone_guard!(read lock as value);
+        value
+    };
+    assert_eq!(owned, "temporary");
+
+    // The modifiers also work in `clone!`
+    clone!(lock mutex as again, read shared.config as current);
+    assert_eq!(again, [1, 2, 3, 4]);
+    assert_eq!(current, "release");
+}
@@ -0,0 +1,78 @@
+// Test: `entries => async block` clones the entries into an async block
+use letclone::clone;
+use std::future::Future;
+use std::pin::pin;
+use std::sync::{Arc, Mutex};
+use std::task::{Context, Poll, Wake, Waker};
+
+/// Polls a future to completion on the current thread
+fn block_on<F: Future>(future: F) -> F::Output {
+    struct Noop;
+    impl Wake for Noop {
+        fn wake(self: Arc<Self>) {}
+    }
+    let waker = Waker::from(Arc::new(Noop));
+    let mut context = Context::from_waker(&waker);
+    let mut future = pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+/// Returns `Pending` once before completing, like a real await point
+struct YieldNow(bool);
+
+impl Future for YieldNow {
+    type Output = ();
+
+    fn poll(mut self: std::pin::Pin<&mut Self>, context: &mut Context<'_>) -> Poll<()> {
+        if self.0 {
+            Poll::Ready(())
+        } else {
+            self.0 = true;
+            context.waker().wake_by_ref();
+            Poll::Pending
+        }
+    }
+}
+
+#[derive(Clone)]
+struct State {
+    events: Arc<Mutex<Vec<String>>>,
+    name: String,
+}
+
+impl State {
+    async fn process(&self) -> usize {
+        YieldNow(false).await;
+        let mut events = self.events.lock().unwrap();
+        events.push(self.name.clone());
+        events.len()
+    }
+}
+
+fn main() {
+    let state = State {
+        events: Arc::new(Mutex::new(Vec::new())),
+        name: String::from("worker"),
+    };
+
+    // Equivalent to: { let state = state.clone(); async move { state.process().await } }
+    let task = clone!(state => async move { state.process().await });
+    assert_eq!(block_on(task), 1);
+
+    // The original is still usable, and `move` is added when missing
+    let task = clone!(state.name as label, state => async {
+        YieldNow(false).await;
+        format!("{label}: {}", state.process().await)
+    });
+    assert_eq!(block_on(task), "worker: 2");
+    assert_eq!(state.events.lock().unwrap().len(), 2);
+
+    // Closures work the same way
+    let count = clone!(state => || state.events.lock().unwrap().len());
+    assert_eq!(count(), 2);
+    assert_eq!(state.name, "worker");
+}
@@ -0,0 +1,21 @@
+// Test: clone_kv! destructuring a borrowed map entry
+use letclone::clone_kv;
+use std::collections::HashMap;
+
+fn main() {
+    let mut map = HashMap::new();
+    map.insert(String::from("name"), String::from("Alice"));
+
+    let key = String::from("name");
+    let entry = map.get_key_value(&key).unwrap();
+    clone_kv!(entry as (k, v));
+    let k: String = k;
+    let v: String = v;
+    assert_eq!(k, "name");
+    assert_eq!(v, "Alice");
+
+    // The map is untouched and still usable
+    map.clear();
+    assert!(map.is_empty());
+    assert_eq!(v, "Alice");
+}
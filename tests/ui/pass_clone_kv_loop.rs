@@ -0,0 +1,26 @@
+// Test: clone_kv! cloning the key and value of a map iteration
+use letclone::clone_kv;
+use std::collections::BTreeMap;
+
+fn main() {
+    let mut map = BTreeMap::new();
+    map.insert(String::from("a"), vec![1]);
+    map.insert(String::from("b"), vec![2, 3]);
+
+    let mut owned: Vec<(String, Vec<i32>)> = Vec::new();
+    for (k, v) in &map {
+        clone_kv!(map => k, v);
+        let k: String = k;
+        let v: Vec<i32> = v;
+        owned.push((k, v));
+    }
+
+    assert_eq!(
+        owned,
+        vec![
+            (String::from("a"), vec![1]),
+            (String::from("b"), vec![2, 3]),
+        ]
+    );
+    assert_eq!(map.len(), 2);
+}
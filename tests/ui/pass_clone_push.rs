@@ -0,0 +1,49 @@
+// Test: clone_push! and clone_insert! adding clones to collections
+use letclone::{clone_insert, clone_push};
+use std::collections::BTreeMap;
+
+struct Line {
+    text: String,
+    id: u32,
+}
+
+struct Report {
+    lines: Vec<String>,
+    by_id: BTreeMap<u32, String>,
+}
+
+fn main() {
+    let lines = vec![
+        Line {
+            text: String::from("first"),
+            id: 1,
+        },
+        Line {
+            text: String::from("second"),
+            id: 2,
+        },
+    ];
+    let mut report = Report {
+        lines: Vec::new(),
+        by_id: BTreeMap::new(),
+    };
+
+    for line in &lines {
+        // Equivalent to: report.lines.push(line.text.clone());
+        clone_push!(report.lines, line.text);
+        clone_insert!(report.by_id, line.id, line.text);
+    }
+    assert_eq!(report.lines, ["first", "second"]);
+    assert_eq!(report.by_id[&2], "second");
+    assert_eq!(lines[0].text, "first");
+
+    // `clone_insert!` evaluates to the previous value
+    let previous = clone_insert!(report.by_id, lines[0].id, lines[1].text);
+    assert_eq!(previous.as_deref(), Some("first"));
+
+    // Plain variables and trailing commas
+    let mut names = Vec::new();
+    let name = String::from("name");
+    clone_push!(names, name,);
+    assert_eq!(names, [name]);
+}
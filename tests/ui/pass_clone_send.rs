@@ -0,0 +1,53 @@
+// Test: clone_send! cloning a value and sending it over a channel
+use letclone::clone_send;
+use std::sync::mpsc;
+
+struct Actor {
+    state: String,
+    tx: mpsc::Sender<String>,
+}
+
+#[derive(Debug)]
+struct Disconnected;
+
+impl From<mpsc::SendError<String>> for Disconnected {
+    fn from(_: mpsc::SendError<String>) -> Self {
+        Disconnected
+    }
+}
+
+fn publish(actor: &Actor) -> Result<(), Disconnected> {
+    // Propagates the send error instead of panicking
+    clone_send!(try actor.tx, actor.state)?;
+    Ok(())
+}
+
+fn main() {
+    let (tx, rx) = mpsc::channel();
+    let actor = Actor {
+        state: String::from("ready"),
+        tx,
+    };
+
+    // Field access for both the channel and the source
+    clone_send!(actor.tx, actor.state);
+    assert_eq!(rx.recv().unwrap(), "ready");
+    assert_eq!(actor.state, "ready");
+
+    // Plain variables
+    let message = String::from("hello");
+    let tx = actor.tx.clone();
+    clone_send!(tx, message);
+    assert_eq!(rx.recv().unwrap(), "hello");
+    assert_eq!(message, "hello");
+
+    publish(&actor).unwrap();
+    assert_eq!(rx.recv().unwrap(), "ready");
+
+    drop(rx);
+    assert!(publish(&actor).is_err());
+
+    // Without `try`, a dropped receiver panics
+    let result = std::panic::catch_unwind(|| clone_send!(tx, message));
+    assert!(result.is_err());
+}
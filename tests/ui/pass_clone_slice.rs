@@ -0,0 +1,42 @@
+// Test: `clone_slice!` clones a slice into an existing one of the same length
+use letclone::clone_slice;
+
+struct Buffer {
+    data: [i32; 4],
+    rows: Vec<String>,
+}
+
+fn main() {
+    let source = [1, 2, 3, 4];
+    let mut target = [0; 4];
+
+    // Equivalent to: target.clone_from_slice(&source);
+    clone_slice!(target, source);
+    assert_eq!(target, [1, 2, 3, 4]);
+    assert_eq!(source, [1, 2, 3, 4]);
+
+    // Field destinations and sub-slice sources
+    let mut buffer = Buffer {
+        data: [0; 4],
+        rows: vec![String::new(); 2],
+    };
+    clone_slice!(buffer.data, source);
+    assert_eq!(buffer.data, [1, 2, 3, 4]);
+    clone_slice!(buffer.data[..2], source[2..]);
+    assert_eq!(buffer.data, [3, 4, 3, 4]);
+
+    // Non-`Copy` elements are cloned, leaving the source intact
+    let names = vec![String::from("a"), String::from("b")];
+    clone_slice!(buffer.rows, names);
+    assert_eq!(buffer.rows, ["a", "b"]);
+    assert_eq!(names, ["a", "b"]);
+
+    // Lengths are compared at run time
+    let short = [9, 9];
+    let result = std::panic::catch_unwind(move || {
+        let mut target = [0; 4];
+        clone_slice!(target, short);
+        target
+    });
+    assert!(result.is_err());
+}
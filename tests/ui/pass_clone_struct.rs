@@ -0,0 +1,44 @@
+// Test: `clone_struct!` builds a struct from clones of a source's fields
+use letclone::clone_struct;
+
+#[derive(Debug, Default, PartialEq)]
+struct Request {
+    url: String,
+    headers: Vec<String>,
+    retries: u32,
+}
+
+struct Template {
+    url: String,
+    headers: Vec<String>,
+}
+
+fn load() -> Template {
+    Template {
+        url: String::from("https://example.com"),
+        headers: vec![String::from("Accept: */*")],
+    }
+}
+
+fn main() {
+    let template = load();
+
+    // Equivalent to:
+    // Request { url: template.url.clone(), headers: template.headers.clone(), ..Default::default() }
+    let request = clone_struct!(Request { url, headers } from template, ..default);
+    assert_eq!(request.url, "https://example.com");
+    assert_eq!(request.headers, ["Accept: */*"]);
+    assert_eq!(request.retries, 0);
+    assert_eq!(template.url, "https://example.com");
+
+    // An explicit base supplies the other fields instead
+    let base = Request { retries: 5, ..Default::default() };
+    let request = clone_struct!(Request { url, ..base } from template);
+    assert_eq!(request.retries, 5);
+    assert!(request.headers.is_empty());
+
+    // A computed source is evaluated once for all fields
+    let request = clone_struct!(Request { url, headers } from load(), ..default);
+    assert_eq!(request.url, "https://example.com");
+    assert_eq!(request.headers.len(), 1);
+}
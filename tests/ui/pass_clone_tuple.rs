@@ -0,0 +1,35 @@
+// Test: `clone_tuple!` clones positional fields into named bindings
+use letclone::clone_tuple;
+use std::cell::Cell;
+
+#[derive(Clone)]
+struct Point(String, String, String);
+
+fn main() {
+    let point = Point(String::from("x"), String::from("y"), String::from("z"));
+
+    // Equivalent to: let x = point.0.clone(); let y = point.1.clone(); let z = point.2.clone();
+    clone_tuple!(point => x, y, z);
+    assert_eq!([x, y, z], ["x", "y", "z"]);
+    assert_eq!(point.0, "x");
+
+    // Trailing fields can be left out, and bindings can be mutable
+    clone_tuple!(point => mut first, second,);
+    first.push('!');
+    assert_eq!((first.as_str(), second.as_str()), ("x!", "y"));
+
+    // Plain tuples and nested sources
+    let pair = (1u8, (String::from("inner"), 2i64));
+    clone_tuple!(pair.1 => label, count);
+    assert_eq!((label.as_str(), count), ("inner", 2));
+
+    // A computed source is evaluated once
+    let calls = Cell::new(0);
+    let load = || {
+        calls.set(calls.get() + 1);
+        point.clone()
+    };
+    clone_tuple!(load() => a, b, c);
+    assert_eq!([a, b, c], ["x", "y", "z"]);
+    assert_eq!(calls.get(), 1);
+}
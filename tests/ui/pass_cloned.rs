@@ -0,0 +1,41 @@
+// Test: `cloned!` as an argument of functions and macros
+use letclone::cloned;
+
+struct Config {
+    name: String,
+    tags: Vec<String>,
+}
+
+fn consume(name: String, tags: Vec<String>) -> String {
+    format!("{}:{}", name, tags.len())
+}
+
+fn main() {
+    let config = Config {
+        name: String::from("app"),
+        tags: vec![String::from("cli")],
+    };
+    let count = 2;
+    let reference = &config.name;
+
+    // Function arguments
+    assert_eq!(consume(cloned!(config.name), cloned!(config.tags)), "app:1");
+
+    // Positional, named and inline-captured `format!` arguments
+    assert_eq!(format!("{} {}", cloned!(config.name), cloned!(count)), "app 2");
+    assert_eq!(format!("{n}", n = cloned!(config.name)), "app");
+    let name = cloned!(*reference);
+    assert_eq!(format!("{name}"), "app");
+    println!("{} {:?}", cloned!(config.name), cloned!(config.tags));
+    assert_eq!(vec![cloned!(config.name), cloned!(*reference)], ["app", "app"]);
+
+    // Method calls and operators on the result
+    assert_eq!(cloned!(config.name).len(), 3);
+    assert_eq!(cloned!(config.name) + "!", "app!");
+    assert_eq!(cloned!(count) * 2, 4);
+
+    // Several entries form a tuple
+    let (n, t) = cloned!(config.name, config.tags,);
+    assert_eq!((n.as_str(), t.len()), ("app", 1));
+    assert_eq!(config.name, "app");
+}
@@ -0,0 +1,45 @@
+// Test: method-call sources with type annotations, and the `collect` modifier
+use letclone::clone;
+use std::collections::{BTreeSet, HashMap};
+
+struct Inventory {
+    items: Vec<String>,
+}
+
+impl Inventory {
+    fn names(&self) -> impl Iterator<Item = &String> {
+        self.items.iter()
+    }
+
+    fn first(&self) -> Option<&String> {
+        self.items.first()
+    }
+}
+
+fn main() {
+    let inventory = Inventory {
+        items: vec![String::from("b"), String::from("a"), String::from("b")],
+    };
+
+    // Method-call sources compose with renames and annotations
+    clone!(inventory.first() as first: Option<&String>);
+    assert_eq!(first.map(String::as_str), Some("b"));
+
+    // `collect` needs the annotation to pick the collection type:
+    // let owned: Vec<String> = IntoIterator::into_iter(inventory.items.iter()).cloned().collect();
+    clone!(collect inventory.items.iter() as owned: Vec<String>);
+    assert_eq!(owned, ["b", "a", "b"]);
+
+    clone!(collect inventory.names() as unique: BTreeSet<String>);
+    assert_eq!(unique.into_iter().collect::<Vec<_>>(), ["a", "b"]);
+
+    // Anything iterable by reference works, and `mut` can be combined
+    clone!(mut collect &inventory.items as sorted: Vec<String>);
+    sorted.sort();
+    assert_eq!(sorted, ["a", "b", "b"]);
+
+    let scores = HashMap::from([(String::from("a"), 1)]);
+    clone!(collect scores.keys() as keys: Vec<String>);
+    assert_eq!(keys, ["a"]);
+    assert_eq!(inventory.items.len(), 3);
+}
@@ -0,0 +1,33 @@
+// Test: combining several sources into one binding clones the whole expression
+use letclone::clone;
+
+struct User {
+    first: String,
+    last: String,
+}
+
+fn main() {
+    let user = User {
+        first: String::from("Ada"),
+        last: String::from("Lovelace"),
+    };
+
+    // The operands are not cloned individually: the whole expression is
+    // evaluated and its result cloned, so a `String` operand is written as an
+    // explicit clone to keep the original usable.
+    // Equivalent to: let full = (user.first.clone() + " " + &user.last).clone();
+    clone!(user.first.clone() + " " + &user.last as full);
+    assert_eq!(full, "Ada Lovelace");
+    assert_eq!(user.first, "Ada");
+    assert_eq!(user.last, "Lovelace");
+
+    // Borrowed operands combine without consuming anything
+    let greeting = "Hello, ";
+    clone!(mut greeting.to_owned() + &user.first as message);
+    message.push('!');
+    assert_eq!(message, "Hello, Ada!");
+
+    // `format!` is often the simplest way to combine borrowed values
+    clone!(format!("{} {}", user.last, user.first) as reversed);
+    assert_eq!(reversed, "Lovelace Ada");
+}
@@ -0,0 +1,67 @@
+// Test: clones in functions and types with const generic parameters
+use letclone::{clone, clone_arr, clone_slice};
+
+#[derive(Clone)]
+struct Buffer<const N: usize> {
+    data: Vec<[u8; N]>,
+    header: [u8; N],
+}
+
+impl<const N: usize> Buffer<N> {
+    fn new() -> Self {
+        Buffer {
+            data: Vec::new(),
+            header: [0; N],
+        }
+    }
+
+    fn snapshot(&self) -> ([u8; N], Vec<[u8; N]>) {
+        clone!(self.header, self.data);
+        (header, data)
+    }
+}
+
+// `[u8; N]: Clone` for every `N`, so cloning through the reference binds an
+// owned array
+fn owned<const N: usize>(arr: &[u8; N]) -> [u8; N] {
+    clone!(arr);
+    arr
+}
+
+// Modifiers and post-operations work on arrays of any length
+fn sorted<const N: usize>(arr: &[u8; N]) -> ([u8; N], [u8; N]) {
+    clone!(deref arr as copy, sorted deref arr as ordered);
+    (copy, ordered)
+}
+
+// Const arguments in paths, array repeat expressions and directives
+fn constructed<const N: usize>() -> (Buffer<N>, [u8; N], [[u8; N]; 2]) {
+    let row = [7; N];
+    clone!(max_size = 4096 send; Buffer::<N>::new() as buffer, [1; N] as ones);
+    let rows = clone_arr!(row, row);
+    (buffer, ones, rows)
+}
+
+fn copy_into<const N: usize>(target: &mut Buffer<N>, source: &[u8; N]) {
+    clone_slice!(target.header, *source);
+}
+
+fn main() {
+    assert_eq!(owned(&[1, 2, 3]), [1, 2, 3]);
+    assert_eq!(sorted(&[3, 1, 2]), ([3, 1, 2], [1, 2, 3]));
+
+    let (buffer, ones, rows) = constructed::<4>();
+    assert_eq!(buffer.header, [0; 4]);
+    assert_eq!(ones, [1; 4]);
+    assert_eq!(rows, [[7; 4]; 2]);
+
+    let mut buffer = Buffer::<3>::new();
+    copy_into(&mut buffer, &[4, 5, 6]);
+    buffer.data.push([9; 3]);
+    let (header, data) = buffer.snapshot();
+    assert_eq!(header, [4, 5, 6]);
+    assert_eq!(data, [[9; 3]]);
+
+    let empty: [u8; 0] = owned(&[]);
+    assert!(empty.is_empty());
+}
@@ -0,0 +1,36 @@
+// Test: `via` a `const fn` method makes `clone!` usable in const contexts
+use letclone::clone;
+
+struct Limits {
+    max: u32,
+    min: u32,
+}
+
+impl Limits {
+    const fn duplicate(&self) -> Limits {
+        Limits {
+            max: self.max,
+            min: self.min,
+        }
+    }
+}
+
+const DEFAULT: Limits = Limits { max: 8, min: 1 };
+
+const COPY: Limits = {
+    // Equivalent to: let limits = DEFAULT.duplicate();
+    clone!(DEFAULT via duplicate as limits);
+    limits
+};
+
+const fn widen(limits: &Limits) -> Limits {
+    clone!(mut limits via duplicate as wider);
+    wider.max *= 2;
+    wider
+}
+
+fn main() {
+    assert_eq!((COPY.max, COPY.min), (8, 1));
+    const WIDE: Limits = widen(&DEFAULT);
+    assert_eq!(WIDE.max, 16);
+}
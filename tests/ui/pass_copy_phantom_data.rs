@@ -0,0 +1,33 @@
+// Test: `copy` modifier with zero-sized `PhantomData` markers
+#![deny(clippy::clone_on_copy)]
+use letclone::clone;
+use std::marker::PhantomData;
+
+struct Typed<T> {
+    id: u32,
+    _marker: PhantomData<T>,
+}
+
+impl<T> Typed<T> {
+    fn retag(&self) -> Typed<T> {
+        // Equivalent to: let _marker = *&self._marker;
+        clone!(copy self._marker, copy self.id);
+        Typed { id, _marker }
+    }
+}
+
+fn main() {
+    let typed: Typed<String> = Typed {
+        id: 7,
+        _marker: PhantomData,
+    };
+    let retagged = typed.retag();
+    assert_eq!(retagged.id, 7);
+    assert_eq!(std::mem::size_of_val(&retagged._marker), 0);
+
+    // A standalone marker binding
+    let _marker: PhantomData<fn() -> u8> = PhantomData;
+    clone!(copy _marker as marker);
+    let _: PhantomData<fn() -> u8> = marker;
+    let _: PhantomData<fn() -> u8> = _marker;
+}
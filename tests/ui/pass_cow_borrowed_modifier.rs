@@ -0,0 +1,41 @@
+// Test: `cow_borrowed` wraps a reference in `Cow::Borrowed` without cloning
+use letclone::clone;
+use std::borrow::Cow;
+
+struct Settings {
+    name: String,
+}
+
+fn label(name: &String, owned: bool) -> Cow<'_, str> {
+    if owned {
+        clone!(cow_owned name as label: Cow<str>);
+        label
+    } else {
+        // Equivalent to: let label: Cow<str> = Cow::Borrowed(name);
+        clone!(cow_borrowed name as label: Cow<str>);
+        label
+    }
+}
+
+fn main() {
+    let s = "text";
+    clone!(cow_borrowed s as c);
+    assert!(matches!(c, Cow::Borrowed("text")));
+
+    // A `&String` coerces to `&str` when the binding is annotated
+    let settings = Settings { name: String::from("app") };
+    clone!(cow_borrowed &settings.name as name: Cow<str>);
+    assert!(matches!(name, Cow::Borrowed(_)));
+    assert!(std::ptr::eq(name.as_ptr(), settings.name.as_ptr()));
+
+    // The borrowed value is only copied when it is modified
+    clone!(mut cow_borrowed s as edited: Cow<str>);
+    edited.to_mut().push('!');
+    assert_eq!(edited, "text!");
+    assert_eq!(s, "text");
+
+    // `cow_owned` and `cow_borrowed` cover both ownership decisions
+    let x = String::from("x");
+    assert!(matches!(label(&x, false), Cow::Borrowed("x")));
+    assert!(matches!(label(&x, true), Cow::Owned(_)));
+}
@@ -0,0 +1,38 @@
+// Test: `cow_owned` modifier wrapping the clone in `Cow::Owned`
+use letclone::clone;
+use std::borrow::Cow;
+
+struct Settings {
+    name: String,
+    tags: Vec<u8>,
+}
+
+struct Builder {
+    name: Cow<'static, str>,
+}
+
+fn label(value: Cow<'_, str>) -> usize {
+    value.len()
+}
+
+fn main() {
+    let settings = Settings {
+        name: String::from("app"),
+        tags: vec![1, 2],
+    };
+
+    // Equivalent to: let name: Cow<'static, str> = Cow::Owned(settings.name.clone());
+    clone!(cow_owned settings.name as name: Cow<'static, str>);
+    assert!(matches!(name, Cow::Owned(_)));
+    let builder = Builder { name };
+    assert_eq!(builder.name, "app");
+
+    // The borrowed type can also be inferred from how the binding is used
+    clone!(cow_owned settings.name as inferred);
+    assert_eq!(label(inferred), 3);
+
+    clone!(mut cow_owned settings.tags as tags: Cow<[u8]>);
+    tags.to_mut().push(3);
+    assert_eq!(&*tags, [1, 2, 3]);
+    assert_eq!(settings.tags, [1, 2]);
+}
@@ -0,0 +1,36 @@
+// Test: clone! with the deref modifier on lock guards
+use letclone::clone;
+use std::sync::{Mutex, RwLock};
+
+struct Shared {
+    name: Mutex<String>,
+    tags: RwLock<Vec<String>>,
+}
+
+fn main() {
+    let shared = Shared {
+        name: Mutex::new(String::from("alice")),
+        tags: RwLock::new(vec![String::from("admin")]),
+    };
+
+    // A named guard: the clone is of the inner `String`, not of the guard
+    let guard = shared.name.lock().unwrap();
+    clone!(deref guard as data);
+    // Equivalent to: let data = (*guard).clone();
+    let data: String = data;
+    assert_eq!(data, "alice");
+    drop(guard);
+    assert!(shared.name.try_lock().is_ok());
+
+    // A temporary guard is released at the end of the generated statement
+    clone!(mut deref shared.name.lock().unwrap() as name);
+    assert!(shared.name.try_lock().is_ok());
+    name.push_str(" smith");
+    assert_eq!(name, "alice smith");
+    assert_eq!(*shared.name.lock().unwrap(), "alice");
+
+    clone!(deref shared.tags.read().unwrap() as tags);
+    let tags: Vec<String> = tags;
+    assert_eq!(tags, vec![String::from("admin")]);
+    assert!(shared.tags.try_write().is_ok());
+}
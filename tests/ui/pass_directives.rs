@@ -0,0 +1,36 @@
+// Test: clone! with shared `mut` and `prefix` directives
+use letclone::clone;
+
+struct Form {
+    title: String,
+    body: String,
+}
+
+fn main() {
+    let form = Form {
+        title: String::from("Hello"),
+        body: String::from("World"),
+    };
+
+    clone!(mut prefix = "new_"; form.title, form.body);
+    // Equivalent to:
+    // let mut new_title = form.title.clone();
+    // let mut new_body = form.body.clone();
+    new_title.push('!');
+    new_body.push('?');
+    assert_eq!(new_title, "Hello!");
+    assert_eq!(new_body, "World?");
+    assert_eq!(form.title, "Hello");
+
+    // Directives compose in any order and also apply to renamed entries
+    clone!(prefix = "old_" mut; form.title as heading, mut form.body);
+    old_heading.clear();
+    old_body.clear();
+    assert!(old_heading.is_empty() && old_body.is_empty());
+
+    // `mut` without a directive list is still the per-entry modifier
+    let prefix = String::from("p");
+    clone!(mut prefix);
+    prefix.push('!');
+    assert_eq!(prefix, "p!");
+}
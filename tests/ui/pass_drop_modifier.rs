@@ -0,0 +1,42 @@
+// Test: `drop` modifier dropping the original after cloning
+use letclone::clone;
+use std::cell::Cell;
+
+struct Big<'a> {
+    data: Vec<u8>,
+    drops: &'a Cell<u32>,
+}
+
+impl Drop for Big<'_> {
+    fn drop(&mut self) {
+        self.drops.set(self.drops.get() + 1);
+    }
+}
+
+fn main() {
+    let drops = Cell::new(0);
+    let big = Big {
+        data: vec![1, 2, 3],
+        drops: &drops,
+    };
+
+    // Equivalent to: let data = big.data.clone(); drop(big);
+    clone!(drop big.data as data);
+    assert_eq!(drops.get(), 1);
+    assert_eq!(data, [1, 2, 3]);
+
+    // Whole variables, combined with `mut`
+    let other = Big {
+        data: vec![4],
+        drops: &drops,
+    };
+    let holder = (other, 5);
+    clone!(mut drop holder.1 as five);
+    five += 1;
+    assert_eq!(five, 6);
+    assert_eq!(drops.get(), 2);
+
+    let name = String::from("name");
+    clone!(drop name as copy);
+    assert_eq!(copy, "name");
+}
@@ -0,0 +1,26 @@
+// Test: clone! with outer attributes on individual entries
+#![deny(unused_variables)]
+use letclone::clone;
+
+struct Data {
+    temp: String,
+    real: String,
+}
+
+fn main() {
+    let data = Data {
+        temp: String::from("temp"),
+        real: String::from("real"),
+    };
+
+    clone!(#[allow(unused_variables)] data.temp, data.real);
+    assert_eq!(real, "real");
+
+    // Attributes on an entry combine with attributes for the whole invocation
+    let name = "outer";
+    {
+        clone!(#[allow(unused_mut)] => #[cfg(test)] mut data.temp as name, mut data.real);
+        assert_eq!(name, "outer");
+        assert_eq!(real, "real");
+    }
+}
@@ -0,0 +1,19 @@
+// Test: clone! re-emits `#[cfg_attr]` on entries, applying it only where the
+// condition holds
+#![deny(unused_variables, unused_mut)]
+use letclone::clone;
+
+fn main() {
+    let data = String::from("data");
+    let label = String::from("label");
+
+    // UI tests are not built with `cfg(test)`, so only the `not(test)` branch
+    // silences the lint
+    clone!(#[cfg_attr(not(test), allow(unused_variables))] data as unused);
+    clone!(#[cfg_attr(not(test), allow(unused_mut))] mut label, #[cfg_attr(test, allow(unused))] data);
+    assert_eq!(label, "label");
+    assert_eq!(data, "data");
+
+    // Combines with invocation-wide attributes
+    clone!(#[cfg_attr(not(test), allow(unused_variables))] => data as first, label as second);
+}
@@ -0,0 +1,45 @@
+// Test: clone! evaluates each source once, left to right
+use letclone::clone;
+use std::cell::{Cell, RefCell};
+
+struct Source {
+    evaluations: Cell<usize>,
+    order: RefCell<Vec<&'static str>>,
+}
+
+impl Source {
+    fn record(&self, name: &'static str) -> String {
+        self.evaluations.set(self.evaluations.get() + 1);
+        self.order.borrow_mut().push(name);
+        format!("{}#{}", name, self.evaluations.get())
+    }
+
+    fn alpha(&self) -> String {
+        self.record("alpha")
+    }
+
+    fn beta(&self) -> String {
+        self.record("beta")
+    }
+
+    fn gamma(&self) -> String {
+        self.record("gamma")
+    }
+}
+
+fn main() {
+    let source = Source {
+        evaluations: Cell::new(0),
+        order: RefCell::new(Vec::new()),
+    };
+
+    clone!(source.gamma(), source.alpha(), mut source.beta(), source.alpha() as again);
+
+    assert_eq!(source.evaluations.get(), 4);
+    assert_eq!(*source.order.borrow(), ["gamma", "alpha", "beta", "alpha"]);
+    assert_eq!(gamma, "gamma#1");
+    assert_eq!(alpha, "alpha#2");
+    beta.push('!');
+    assert_eq!(beta, "beta#3!");
+    assert_eq!(again, "alpha#4");
+}
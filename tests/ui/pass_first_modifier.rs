@@ -0,0 +1,29 @@
+// Test: `first` modifier clones the first item of an iterator
+use letclone::clone;
+
+struct Queue {
+    jobs: Vec<String>,
+}
+
+fn main() {
+    let items = vec![String::from("a"), String::from("b")];
+
+    // Equivalent to: let head = items.iter().next().cloned();
+    clone!(first items.iter() as head);
+    let head: Option<String> = head;
+    assert_eq!(head.as_deref(), Some("a"));
+    assert_eq!(items.len(), 2);
+
+    let empty: Vec<String> = Vec::new();
+    clone!(first empty.iter() as nothing);
+    assert_eq!(nothing, None);
+
+    // Works with any iterator adapter and field sources
+    let queue = Queue {
+        jobs: vec![String::from("build"), String::from("test")],
+    };
+    clone!(mut first queue.jobs.iter().rev() as last);
+    assert_eq!(last.as_deref(), Some("test"));
+    last = None;
+    assert_eq!(last, None);
+}
@@ -0,0 +1,29 @@
+// Test: `flatten` modifier for nested options
+use letclone::clone;
+
+struct Cache {
+    entry: Option<Option<String>>,
+}
+
+fn main() {
+    let cache = Cache {
+        entry: Some(Some(String::from("value"))),
+    };
+
+    // Equivalent to: let value = cache.entry.clone().flatten();
+    clone!(flatten cache.entry as value);
+    let value: Option<String> = value;
+    assert_eq!(value.as_deref(), Some("value"));
+    assert_eq!(cache.entry, Some(Some(String::from("value"))));
+
+    let missing: Option<Option<String>> = Some(None);
+    clone!(mut flatten missing as inner);
+    assert_eq!(inner, None);
+    inner = Some(String::from("filled"));
+    assert_eq!(inner.as_deref(), Some("filled"));
+
+    // Composes with other modifiers
+    let nested_ref = &cache.entry;
+    clone!(deref flatten nested_ref as through_ref);
+    assert_eq!(through_ref.as_deref(), Some("value"));
+}
@@ -0,0 +1,34 @@
+// Test: cloning `fn` pointers, `Copy` closures and non-`Copy` closures
+#![deny(clippy::clone_on_copy)]
+use letclone::clone;
+
+fn double(x: u32) -> u32 {
+    x * 2
+}
+
+struct Handler {
+    callback: fn(u32) -> u32,
+}
+
+fn main() {
+    let handler = Handler { callback: double };
+
+    // `copy` on a `fn` pointer: let callback = *&handler.callback;
+    clone!(copy handler.callback as callback);
+    assert_eq!(callback(2), 4);
+    assert_eq!((handler.callback)(3), 6);
+
+    // A closure capturing only `Copy` values is itself `Copy`
+    let offset = 10;
+    let add = move |x: u32| x + offset;
+    clone!(copy add as add_copy);
+    assert_eq!(add_copy(1), 11);
+    assert_eq!(add(2), 12);
+
+    // A closure capturing a `String` is `Clone` but not `Copy`
+    let name = String::from("Ada");
+    let greet = move || format!("Hello, {}!", name);
+    clone!(greet as greet_clone);
+    assert_eq!(greet_clone(), "Hello, Ada!");
+    assert_eq!(greet(), "Hello, Ada!");
+}
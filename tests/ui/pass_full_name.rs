@@ -0,0 +1,37 @@
+// Test: `full_name` directive joining path segments into the binding name
+#![deny(warnings)]
+use letclone::clone;
+
+mod config {
+    pub mod database {
+        pub const URL: &str = "postgres://db";
+    }
+    pub mod cache {
+        pub const URL: &str = "redis://cache";
+    }
+}
+
+struct Settings {
+    name: String,
+}
+
+fn main() {
+    // Equivalent to:
+    // let config_database_URL = config::database::URL.clone();
+    // let config_cache_URL = config::cache::URL.clone();
+    clone!(full_name; config::database::URL, config::cache::URL);
+    assert_eq!(config_database_URL, "postgres://db");
+    assert_eq!(config_cache_URL, "redis://cache");
+
+    // Renames, single-segment paths and fields keep their names; other
+    // directives apply to the joined name
+    let settings = Settings {
+        name: String::from("app"),
+    };
+    let local = 1;
+    clone!(full_name prefix = "my_"; config::database::URL as url, local, settings.name, config::cache::URL);
+    assert_eq!(my_url, "postgres://db");
+    assert_eq!(my_local, 1);
+    assert_eq!(my_name, "app");
+    assert_eq!(my_config_cache_URL, "redis://cache");
+}
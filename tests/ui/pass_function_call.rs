@@ -0,0 +1,18 @@
+// Test: clone! with a function-call source
+use letclone::clone;
+
+fn make_config() -> String {
+    String::from("config")
+}
+
+mod defaults {
+    pub fn name() -> String {
+        String::from("default")
+    }
+}
+
+fn main() {
+    clone!(make_config(), defaults::name());
+    assert_eq!(make_config, "config");
+    assert_eq!(name, "default");
+}
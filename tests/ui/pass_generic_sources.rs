@@ -0,0 +1,49 @@
+// Test: clones in generic code, where `Clone` depends on type parameter bounds
+use letclone::clone;
+
+struct Wrapper<T> {
+    inner: T,
+}
+
+// With `T: Clone`, `Vec<T>: Clone` holds and `items.clone()` clones the vector
+fn owned<T: Clone>(items: &Vec<T>) -> Vec<T> {
+    clone!(items);
+    items
+}
+
+// `deref` requires an owned clone and works with type parameters
+fn deref_owned<T: Clone>(items: &Vec<T>) -> Vec<T> {
+    clone!(deref items as owned);
+    owned
+}
+
+// Without the bound only the reference is `Clone`, so the clone is a copy of
+// the reference
+#[allow(noop_method_call)]
+fn unbounded<T>(items: &Vec<T>) -> &Vec<T> {
+    clone!(items as copy);
+    copy
+}
+
+// Paths with generic arguments and qualified paths derive their names from
+// the last segment
+fn constructed<T: Default + Clone>() -> (T, Vec<T>, T) {
+    clone!(T::default() as value, Vec::<T>::new(), <T as Default>::default());
+    (value, new, default)
+}
+
+fn fields<T: Clone>(wrapper: &Wrapper<Vec<T>>) -> (Vec<T>, Vec<T>) {
+    clone!(wrapper.inner, collect wrapper.inner.iter() as collected: Vec<T>);
+    (inner, collected)
+}
+
+fn main() {
+    let items = vec![String::from("a")];
+    assert_eq!(owned(&items), items);
+    assert_eq!(deref_owned(&items), items);
+    assert!(std::ptr::eq(unbounded(&items), &items));
+    assert_eq!(constructed::<u8>(), (0, Vec::new(), 0));
+
+    let wrapper = Wrapper { inner: vec![1, 2] };
+    assert_eq!(fields(&wrapper), (vec![1, 2], vec![1, 2]));
+}
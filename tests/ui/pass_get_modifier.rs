@@ -0,0 +1,58 @@
+// Test: clone! with the get modifier on OnceLock/OnceCell
+use letclone::clone;
+use std::cell::OnceCell;
+use std::sync::OnceLock;
+
+static CONFIG: OnceLock<String> = OnceLock::new();
+static MISSING: OnceLock<String> = OnceLock::new();
+
+struct App {
+    name: OnceCell<String>,
+}
+
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload.downcast_ref::<&str>().unwrap().to_string(),
+    }
+}
+
+fn main() {
+    CONFIG.set(String::from("release")).unwrap();
+
+    clone!(get CONFIG as config);
+    // Equivalent to: let config = CONFIG.get().expect("...").clone();
+    let config: String = config;
+    assert_eq!(config, "release");
+
+    let app = App {
+        name: OnceCell::new(),
+    };
+    app.name.set(String::from("letclone")).unwrap();
+    clone!(mut get("app name must be set") app.name);
+    name.push_str("-app");
+    assert_eq!(name, "letclone-app");
+
+    // An empty cell panics with the default message
+    let default = std::panic::catch_unwind(|| {
+        clone!(get MISSING as missing);
+        missing
+    })
+    .unwrap_err();
+    assert_eq!(panic_message(default), "`MISSING` is not initialized");
+
+    // ...or with the configured one
+    let configured = std::panic::catch_unwind(|| {
+        clone!(get("missing must be set") MISSING as missing);
+        missing
+    })
+    .unwrap_err();
+    assert_eq!(panic_message(configured), "missing must be set");
+
+    // A function named `get` is still a plain source
+    fn get(value: u32) -> u32 {
+        value
+    }
+    clone!(get(5) as five);
+    assert_eq!(five, 5);
+}
@@ -0,0 +1,47 @@
+// Test: `getter` modifier clones through a getter method named after a field
+use letclone::clone;
+
+mod model {
+    pub struct User {
+        name: String,
+        tags: Vec<String>,
+    }
+
+    impl User {
+        pub fn new(name: &str) -> Self {
+            User {
+                name: name.to_string(),
+                tags: vec![String::from("admin")],
+            }
+        }
+
+        pub fn name(&self) -> &String {
+            &self.name
+        }
+
+        pub fn tags(&self) -> &[String] {
+            &self.tags
+        }
+    }
+}
+
+struct Session {
+    user: model::User,
+}
+
+fn main() {
+    let user = model::User::new("alice");
+
+    // Equivalent to: let name = user.name().clone();
+    clone!(getter user.name as name);
+    let name: String = name;
+    assert_eq!(name, "alice");
+
+    // The binding is named after the field without a rename
+    let session = Session { user };
+    clone!(getter session.user.name, getter session.user.tags as tags);
+    let tags: &[String] = tags;
+    assert_eq!(name, "alice");
+    assert_eq!(tags, ["admin"]);
+    assert_eq!(session.user.name(), "alice");
+}
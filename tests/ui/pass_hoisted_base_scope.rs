@@ -0,0 +1,56 @@
+// Test: a base shared by `hoist` and its temporaries are dropped at the end of the clone
+use letclone::clone;
+use std::cell::{Cell, RefCell};
+use std::sync::Mutex;
+
+struct Config {
+    name: String,
+    port: u16,
+}
+
+fn main() {
+    let cell = RefCell::new(Config {
+        name: String::from("main"),
+        port: 80,
+    });
+
+    // Equivalent to:
+    // let (name, port) = { let __base0 = cell.borrow(); (__base0.name.clone(), __base0.port.clone()) };
+    clone!(hoist; cell.borrow().name as name, mut cell.borrow().port as port);
+    cell.borrow_mut().port = 8080;
+    port += 1;
+    assert_eq!((name.as_str(), port), ("main", 81));
+
+    // A lock guard is released too, so locking again does not deadlock
+    let mutex = Mutex::new(Config {
+        name: String::from("locked"),
+        port: 1,
+    });
+    clone!(hoist; mutex.lock().unwrap().name as locked, mutex.lock().unwrap().port as lock_port);
+    mutex.lock().unwrap().port = 2;
+    assert_eq!((locked.as_str(), lock_port), ("locked", 1));
+
+    // Entries in between and later entries reading earlier bindings
+    let loads = Cell::new(0);
+    let load = || {
+        loads.set(loads.get() + 1);
+        Config {
+            name: String::from("loaded"),
+            port: 3,
+        }
+    };
+    clone!(hoist; load().name as first, first.len() as len, load().port as _port, load().port as third);
+    assert_eq!(loads.get(), 1);
+    assert_eq!((first.as_str(), len, third), ("loaded", 6, 3));
+
+    // Attributes shared by the entries also apply to the base
+    clone!(#[cfg(any())] => hoist; load().name as skipped, load().port as skipped_port);
+    clone!(hoist; #[cfg(any())] load().name as one, #[cfg(any())] load().port as other);
+    assert_eq!(loads.get(), 1);
+
+    // Modifiers that mutate the clone keep the binding mutable
+    let lists = || (vec![3, 1, 2], vec![0]);
+    clone!(hoist; sorted lists().0 as sorted, sorted lists().1 as extended);
+    extended.push(9);
+    assert_eq!((sorted, extended), (vec![1, 2, 3], vec![0, 9]));
+}
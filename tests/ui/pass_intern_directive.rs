@@ -0,0 +1,59 @@
+// Test: `intern = <path>` directive calls an interner with `&source`
+use letclone::clone;
+use std::collections::HashSet;
+use std::sync::Arc;
+
+mod interner {
+    use std::cell::RefCell;
+    use std::collections::HashSet;
+    use std::sync::Arc;
+
+    thread_local! {
+        static STRINGS: RefCell<HashSet<Arc<str>>> = RefCell::new(HashSet::new());
+    }
+
+    pub fn intern(value: &String) -> Arc<str> {
+        STRINGS.with_borrow_mut(|strings| {
+            if let Some(existing) = strings.get(value.as_str()) {
+                return existing.clone();
+            }
+            let interned: Arc<str> = Arc::from(value.as_str());
+            strings.insert(interned.clone());
+            interned
+        })
+    }
+}
+
+struct Record {
+    name: String,
+    tag: String,
+}
+
+fn main() {
+    let first = Record {
+        name: String::from("ann"),
+        tag: String::from("admin"),
+    };
+    let second = Record {
+        name: String::from("ann"),
+        tag: String::from("user"),
+    };
+
+    // Equivalent to: let name = interner::intern(&first.name);
+    clone!(intern = interner::intern; first.name as name);
+    assert_eq!(&*name, "ann");
+    assert_eq!(first.name, "ann");
+
+    // Equal sources share one allocation
+    clone!(intern = interner::intern; second.name as again, second.tag as tag);
+    assert!(Arc::ptr_eq(&name, &again));
+    assert_eq!(&*tag, "user");
+
+    // Combines with other directives and a type annotation
+    clone!(mut intern = interner::intern; first.tag as role: Arc<str>);
+    role = interner::intern(&second.tag);
+    assert!(Arc::ptr_eq(&role, &tag));
+
+    let unique: HashSet<Arc<str>> = [name, again, tag, role].into_iter().collect();
+    assert_eq!(unique.len(), 2);
+}
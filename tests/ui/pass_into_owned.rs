@@ -0,0 +1,29 @@
+// Test: clone! with the into_owned modifier on Cow fields
+use letclone::clone;
+use std::borrow::Cow;
+
+struct Config<'a> {
+    path: Cow<'a, str>,
+    fallback: Cow<'a, str>,
+}
+
+fn main() {
+    let config = Config {
+        path: Cow::Borrowed("/etc/app.toml"),
+        fallback: Cow::Owned(String::from("/tmp/app.toml")),
+    };
+
+    clone!(into_owned config.path as path, into_owned config.fallback);
+    // Equivalent to:
+    // let path = config.path.clone().into_owned();
+    // let fallback = config.fallback.clone().into_owned();
+    let path: String = path;
+    let fallback: String = fallback;
+    assert_eq!(path, "/etc/app.toml");
+    assert_eq!(fallback, "/tmp/app.toml");
+
+    clone!(mut into_owned config.path as owned);
+    owned.push_str(".bak");
+    assert_eq!(owned, "/etc/app.toml.bak");
+    assert_eq!(config.path, "/etc/app.toml");
+}
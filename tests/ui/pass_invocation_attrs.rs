@@ -0,0 +1,30 @@
+// Test: clone! with attributes applied to the whole invocation
+use letclone::clone;
+
+struct Person {
+    name: String,
+    email: String,
+}
+
+fn main() {
+    let person = Person {
+        name: String::from("inner name"),
+        email: String::from("inner email"),
+    };
+
+    // Excluded under a non-test build: the outer bindings stay visible
+    let name = "outer name";
+    let email = "outer email";
+    {
+        clone!(#[cfg(test)] => person.name, person.email);
+        assert_eq!(name, "outer name");
+        assert_eq!(email, "outer email");
+    }
+
+    // Included: the clones shadow the outer bindings
+    {
+        clone!(#[cfg(not(test))] #[allow(unused_mut)] => mut person.name, person.email);
+        assert_eq!(name, "inner name");
+        assert_eq!(email, "inner email");
+    }
+}
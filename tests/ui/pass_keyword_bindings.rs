@@ -0,0 +1,49 @@
+// Test: bindings named after keywords are written as raw identifiers
+use letclone::clone;
+
+#[derive(Default)]
+struct Token {
+    r#type: String,
+    r#loop: u32,
+    kind: String,
+}
+
+impl Token {
+    fn r#match(&self) -> bool {
+        self.r#loop > 0
+    }
+}
+
+fn main() {
+    let token = Token {
+        r#type: String::from("ident"),
+        r#loop: 2,
+        kind: String::from("word"),
+    };
+
+    // Equivalent to: let r#type = token.r#type.clone();
+    clone!(token.r#type, token.r#match());
+    assert_eq!(r#type, "ident");
+    assert!(r#match);
+
+    // A keyword rename
+    clone!(token.kind as r#match);
+    assert_eq!(r#match, "word");
+
+    // Names built by directives are escaped when they are keywords
+    clone!(prefix = "mo"; token.r#loop as ve);
+    assert_eq!(r#move, 2);
+    clone!(prefix = ""; token.r#type);
+    assert_eq!(r#type, "ident");
+    clone!(suffix_index; token.r#type);
+    assert_eq!(type_0, "ident");
+
+    // String renames computed by other macros
+    clone!(token.kind as "type", token.r#loop as "r#while");
+    assert_eq!(r#type, "word");
+    assert_eq!(r#while, 2);
+
+    let r#fn = Token::default();
+    clone!(r#fn.kind as r#async);
+    assert!(r#async.is_empty());
+}
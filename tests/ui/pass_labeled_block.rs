@@ -0,0 +1,37 @@
+// Test: clone! inside labeled blocks and loops
+use letclone::clone;
+
+struct Config {
+    name: String,
+}
+
+fn main() {
+    let config = Config {
+        name: String::from("outer"),
+    };
+    let name = "shadowed";
+
+    // The binding is scoped to the labeled block and can escape as its value
+    let escaped = 'outer: {
+        clone!(config.name);
+        if name.is_empty() {
+            break 'outer String::new();
+        }
+        name
+    };
+    assert_eq!(escaped, "outer");
+    assert_eq!(name, "shadowed");
+
+    // Labeled loops work the same way
+    let mut seen = Vec::new();
+    'rows: for row in 0..3 {
+        clone!(mut config.name);
+        name.push_str(&row.to_string());
+        if row == 1 {
+            seen.push(name);
+            break 'rows;
+        }
+        seen.push(name);
+    }
+    assert_eq!(seen, ["outer0", "outer1"]);
+}
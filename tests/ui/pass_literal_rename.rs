@@ -0,0 +1,17 @@
+// Test: a literal source is bound as is when renamed
+use letclone::clone;
+
+fn main() {
+    // Equivalent to: let answer = 42;
+    clone!(42 as answer, "text" as text, mut 1.5 as ratio);
+    assert_eq!(answer, 42);
+    let text: &'static str = text;
+    assert_eq!(text, "text");
+    ratio *= 2.0;
+    assert_eq!(ratio, 3.0);
+
+    // Modifiers still apply to the literal
+    clone!(cast 7 as wide: u64, wrap(Box) 'c' as boxed);
+    assert_eq!(wide, 7u64);
+    assert_eq!(*boxed, 'c');
+}
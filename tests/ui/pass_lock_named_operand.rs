@@ -0,0 +1,22 @@
+// Test: variables named `lock` or `read` can be operands of `&`, `*` and `<`
+use letclone::clone;
+use std::sync::{Mutex, RwLock};
+
+fn main() {
+    let read = 0b1010u8;
+    let lock = 3u32;
+    let mask = 0b0110u8;
+
+    clone!(read & mask as bits);
+    // Equivalent to: let bits = (read & mask).clone();
+    assert_eq!(bits, 0b0010);
+
+    clone!(lock * 2 as doubled, read < mask as below);
+    assert_eq!((doubled, below), (6, false));
+
+    // The modifiers still apply to lock sources
+    let state = Mutex::new(vec![1, 2]);
+    let config = RwLock::new(String::from("debug"));
+    clone!(lock state as data, read config as level);
+    assert_eq!((data, level.as_str()), (vec![1, 2], "debug"));
+}
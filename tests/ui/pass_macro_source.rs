@@ -0,0 +1,29 @@
+// Test: macro invocations as renamed sources
+use letclone::clone;
+
+macro_rules! shared {
+    () => {
+        &DEFAULT
+    };
+}
+
+static DEFAULT: [u8; 2] = [1, 2];
+
+fn main() {
+    // Equivalent to: let v = vec![1, 2, 3];
+    clone!(vec![1, 2, 3] as v, format!("{}-{}", 1, 2) as label);
+    let v: Vec<i32> = v;
+    let label: String = label;
+    assert_eq!(v, [1, 2, 3]);
+    assert_eq!(label, "1-2");
+
+    // Other macros are cloned: this clones the referenced array
+    clone!(shared!() as copy);
+    let copy: [u8; 2] = copy;
+    assert_eq!(copy, DEFAULT);
+
+    // Modifiers apply to the macro result
+    clone!(boxed_str format!("{}", 7) as seven, sorted vec![3, 1, 2] as sorted);
+    assert_eq!(&*seven, "7");
+    assert_eq!(sorted, [1, 2, 3]);
+}
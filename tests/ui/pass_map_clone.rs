@@ -0,0 +1,35 @@
+// Test: clone! with the map_clone modifier on Option<Box<T>> links
+use letclone::clone;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Node {
+    value: i32,
+    next: Option<Box<Node>>,
+}
+
+fn main() {
+    let tail = Node {
+        value: 2,
+        next: None,
+    };
+    let head = Node {
+        value: 1,
+        next: Some(Box::new(tail.clone())),
+    };
+
+    clone!(map_clone head.next);
+    // Equivalent to: let next = head.next.as_ref().map(Clone::clone);
+    let next: Option<Box<Node>> = next;
+    assert_eq!(next.as_deref(), Some(&tail));
+
+    clone!(map_clone tail.next as after_tail);
+    let after_tail: Option<Box<Node>> = after_tail;
+    assert!(after_tail.is_none());
+
+    clone!(mut map_clone head.next as link);
+    if let Some(node) = link.as_mut() {
+        node.value = 20;
+    }
+    assert_eq!(link.map(|node| node.value), Some(20));
+    assert_eq!(head.next.map(|node| node.value), Some(2));
+}
@@ -0,0 +1,30 @@
+// Test: `max_size = N` directive accepting clones within the limit
+use letclone::clone;
+
+#[derive(Clone)]
+struct Small {
+    id: u32,
+    flags: [u8; 4],
+}
+
+fn main() {
+    let small = Small {
+        id: 1,
+        flags: [0; 4],
+    };
+    let name = String::from("name");
+
+    clone!(max_size = 8; small, small.id);
+    assert_eq!(small.id + id, 2);
+
+    // Heap data is not counted: a `String` is three words
+    clone!(max_size = 24; name, mut small.flags as flags);
+    flags[0] = 1;
+    assert_eq!(name, "name");
+    assert_eq!(flags, [1, 0, 0, 0]);
+
+    {
+        clone!(max_size = 24 mut; ref mut name as edited);
+        edited.push('!');
+    }
+}
@@ -0,0 +1,35 @@
+// Test: variables named like modifiers are cloned when a clause follows them
+use letclone::clone;
+
+fn main() {
+    let first = String::from("head");
+    let take = vec![1, 2, 3];
+    let copy = String::from("copy");
+
+    clone!(first where |f: &String| !f.is_empty());
+    // Equivalent to:
+    // let first = first.clone();
+    // debug_assert!((|f: &String| !f.is_empty())(&first));
+    assert_eq!(first, "head");
+
+    clone!(take via to_owned as items);
+    assert_eq!(items, [1, 2, 3]);
+
+    clone!(copy as other, take where |t: &Vec<i32>| t.len() == 3);
+    assert_eq!((other.as_str(), take.len()), ("copy", 3));
+
+    // Modifiers still apply to sources that start with a path keyword
+    let value = Value { inner: Some(4) };
+    assert_eq!(value.get(), Some(4));
+}
+
+struct Value {
+    inner: Option<i32>,
+}
+
+impl Value {
+    fn get(&self) -> Option<i32> {
+        clone!(copy self.inner as inner);
+        inner
+    }
+}
@@ -0,0 +1,10 @@
+// Test: `must_use` directive compiles cleanly when the clones are used
+#![deny(warnings)]
+use letclone::clone;
+
+fn main() {
+    let expensive = vec![0u8; 1024];
+    clone!(must_use; mut expensive as buffer, expensive);
+    buffer.push(1);
+    assert_eq!(buffer.len() + expensive.len(), 2049);
+}
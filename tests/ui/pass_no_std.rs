@@ -0,0 +1,90 @@
+// Test: the macros work in `#![no_std]` crates without `alloc`.
+//
+// `std` is linked under another name only to provide the runtime of the test
+// binary, so any generated `::std` or `::alloc` path fails to resolve.
+#![no_std]
+extern crate std as runtime;
+
+use core::cell::OnceCell;
+use core::sync::atomic::AtomicU32;
+use letclone::{atomic_load, clone, clone_arr, clone_assign, clone_tuple, cloned, try_clone};
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Point(i32, i32);
+
+#[derive(Clone)]
+struct Sensor {
+    reading: Option<Option<u16>>,
+    origin: Point,
+    samples: [u8; 4],
+    calibration: OnceCell<i32>,
+    status: Result<u8, u8>,
+}
+
+struct Port(u8);
+
+impl Port {
+    fn try_clone(&self) -> Result<Port, ()> {
+        Ok(Port(self.0))
+    }
+}
+
+fn duplicate(port: &Port) -> Result<u8, ()> {
+    try_clone!(port as copy);
+    Ok(copy.0)
+}
+
+fn main() {
+    let sensor = Sensor {
+        reading: Some(Some(7)),
+        origin: Point(1, 2),
+        samples: [1, 2, 3, 4],
+        calibration: OnceCell::new(),
+        status: Ok(1),
+    };
+    sensor.calibration.set(-3).unwrap();
+
+    // Plain forms
+    clone!(sensor.origin, mut sensor.samples, sensor.origin.0 as x);
+    samples[0] = 9;
+    assert_eq!((origin, samples[0], x), (Point(1, 2), 9, 1));
+
+    // Modifiers that only need `core`
+    clone!(
+        flatten sensor.reading as reading,
+        map_clone sensor.reading as outer,
+        copy sensor.origin as copied,
+        get sensor.calibration as calibration,
+        ok sensor.status as status,
+        first sensor.samples.iter() as head,
+        black_box sensor.origin as boxed,
+        cast sensor.origin.1 as wide: i64,
+    );
+    assert_eq!(reading, Some(7));
+    assert_eq!(outer, Some(Some(7)));
+    assert_eq!((copied, boxed, wide), (Point(1, 2), Point(1, 2), 2));
+    assert_eq!((calibration, status, head), (-3, Some(1), Some(1)));
+
+    // Directives and post-conditions
+    clone!(max_size = 8 send; sensor.origin as checked where |p| p.0 > 0);
+    assert_eq!(checked, Point(1, 2));
+    {
+        clone!(drop sensor.origin as moved);
+        assert_eq!(moved, Point(1, 2));
+    }
+
+    // The other macros
+    let level = AtomicU32::new(5);
+    atomic_load!(level as current);
+    assert_eq!(current, 5);
+    let pair: [Point; 2] = clone_arr!(origin, copied);
+    assert_eq!(pair[1], Point(1, 2));
+    assert_eq!(cloned!(origin), Point(1, 2));
+    clone_tuple!(origin => left, right);
+    assert_eq!(left + right, 3);
+    let mut target = Point(0, 0);
+    assert_eq!(target.0, 0);
+    clone_assign!(target = origin);
+    assert_eq!(target, origin);
+    assert_eq!(duplicate(&Port(4)), Ok(4));
+}
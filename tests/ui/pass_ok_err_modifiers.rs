@@ -0,0 +1,31 @@
+// Test: `ok` and `err` modifiers for `Result` sources
+use letclone::clone;
+
+struct Response {
+    body: Result<String, String>,
+}
+
+fn main() {
+    let resp = Response {
+        body: Ok(String::from("payload")),
+    };
+
+    // Equivalent to: let body = resp.body.clone().ok();
+    clone!(ok resp.body as body, err resp.body as error);
+    let body: Option<String> = body;
+    assert_eq!(body.as_deref(), Some("payload"));
+    assert_eq!(error, None);
+    assert!(resp.body.is_ok());
+
+    let failed: Result<u32, String> = Err(String::from("timeout"));
+    clone!(ok failed as value, mut err failed as reason);
+    assert_eq!(value, None);
+    assert_eq!(reason.as_deref(), Some("timeout"));
+    reason = None;
+    assert_eq!(reason, None);
+
+    // Composes with other modifiers
+    let nested: Result<Option<u32>, ()> = Ok(Some(1));
+    clone!(ok flatten nested as inner);
+    assert_eq!(inner, Some(1));
+}
@@ -0,0 +1,28 @@
+// Test: parenthesized sources derive their name from the inner expression
+use letclone::clone;
+
+struct Person {
+    name: String,
+    tags: (Vec<u8>, u32),
+}
+
+fn main() {
+    let person = Person {
+        name: String::from("Alice"),
+        tags: (vec![1], 2),
+    };
+
+    // Equivalent to: let name = (person.name).clone();
+    clone!((person.name), mut ((person.tags).0), (person).tags.1 as count);
+    assert_eq!(name, "Alice");
+    field_0.push(2);
+    assert_eq!(field_0, [1, 2]);
+    assert_eq!(count, 2);
+
+    let a = 1;
+    let b = 2;
+    clone!((a + b) as sum, (a));
+    assert_eq!(sum, 3);
+    assert_eq!(a, 1);
+
+}
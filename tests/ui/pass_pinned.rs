@@ -0,0 +1,32 @@
+// Test: clone! on pinned smart pointers clones the pointer and its pointee
+use letclone::clone;
+use std::pin::Pin;
+use std::rc::Rc;
+
+struct Task {
+    name: Pin<Box<String>>,
+}
+
+fn main() {
+    let pinned: Pin<Box<String>> = Box::pin(String::from("pinned"));
+
+    // Equivalent to: let copy = pinned.clone();
+    clone!(pinned as copy);
+    let copy: Pin<Box<String>> = copy;
+    assert_eq!(*copy, "pinned");
+    // A new allocation: the original pointee is not moved
+    assert!(!std::ptr::eq(&*copy, &*pinned));
+
+    let task = Task {
+        name: Box::pin(String::from("task")),
+    };
+    clone!(mut task.name);
+    name.as_mut().get_mut().push('!');
+    assert_eq!(*name, "task!");
+    assert_eq!(*task.name, "task");
+
+    // Shared pointers keep pointing to the same value
+    let shared: Pin<Rc<String>> = Rc::pin(String::from("shared"));
+    clone!(shared as other);
+    assert!(std::ptr::eq(&*other, &*shared));
+}
@@ -0,0 +1,34 @@
+// Test: `dedup` and `reverse` modifiers chain with `sorted` in written order
+use letclone::clone;
+
+struct Data {
+    items: Vec<i32>,
+}
+
+fn main() {
+    let data = Data {
+        items: vec![3, 1, 3, 2, 1, 2],
+    };
+
+    // Equivalent to: let mut items = data.items.clone(); items.sort(); items.dedup();
+    clone!(sorted dedup data.items as items);
+    assert_eq!(items, [1, 2, 3]);
+    assert_eq!(data.items, [3, 1, 3, 2, 1, 2]);
+
+    // `dedup` alone only removes consecutive duplicates
+    let runs = vec![1, 1, 2, 2, 1];
+    clone!(dedup runs as collapsed, reverse runs as reversed);
+    assert_eq!(collapsed, [1, 2, 1]);
+    assert_eq!(reversed, [1, 2, 2, 1, 1]);
+
+    // The order of the modifiers is the order of the operations
+    clone!(sorted reverse dedup data.items as descending);
+    assert_eq!(descending, [3, 2, 1]);
+    clone!(dedup sorted data.items as sorted_only);
+    assert_eq!(sorted_only, [1, 1, 2, 2, 3, 3]);
+
+    // The binding is mutable
+    clone!(reverse data.items as mut_reversed);
+    mut_reversed.push(0);
+    assert_eq!(mut_reversed, [2, 1, 2, 3, 1, 3, 0]);
+}
@@ -0,0 +1,44 @@
+// Test: `q` propagates `None` and `Err` clones with `?`
+use letclone::clone;
+
+struct Config {
+    name: Option<String>,
+    port: Result<u16, String>,
+}
+
+fn name(config: &Config) -> Option<usize> {
+    // Equivalent to: let name = config.name.clone()?;
+    clone!(q config.name as name);
+    let name: String = name;
+    Some(name.len())
+}
+
+fn port(config: &Config) -> Result<u16, String> {
+    clone!(q config.port as port);
+    Ok(port + 1)
+}
+
+fn boxed_name(config: &Config) -> Option<Box<str>> {
+    // Modifiers after `q` apply to the inner value
+    clone!(q boxed_str config.name as name);
+    Some(name)
+}
+
+fn main() {
+    let config = Config {
+        name: Some(String::from("app")),
+        port: Ok(8080),
+    };
+    assert_eq!(name(&config), Some(3));
+    assert_eq!(port(&config), Ok(8081));
+    assert_eq!(boxed_name(&config).as_deref(), Some("app"));
+    assert_eq!(config.name.as_deref(), Some("app"));
+
+    let empty = Config {
+        name: None,
+        port: Err(String::from("unset")),
+    };
+    assert_eq!(name(&empty), None);
+    assert_eq!(port(&empty), Err(String::from("unset")));
+    assert_eq!(boxed_name(&empty), None);
+}
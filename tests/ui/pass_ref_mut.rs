@@ -0,0 +1,37 @@
+// Test: clone! with `ref mut` binds a `&mut` to a fresh clone
+use letclone::clone;
+
+struct Data {
+    items: Vec<i32>,
+}
+
+fn extend(buf: &mut Vec<i32>, value: i32) {
+    buf.push(value);
+}
+
+fn main() {
+    let data = Data { items: vec![1, 2] };
+    let buf = String::from("hello");
+
+    {
+        clone!(ref mut buf, ref mut data.items);
+        // Equivalent to:
+        // let mut __buf = buf.clone();
+        // let buf = &mut __buf;
+        let buf: &mut String = buf;
+        buf.push_str(" world");
+        assert_eq!(buf, "hello world");
+
+        extend(items, 3);
+        assert_eq!(*items, [1, 2, 3]);
+    }
+
+    // The originals are untouched
+    assert_eq!(buf, "hello");
+    assert_eq!(data.items, [1, 2]);
+
+    clone!(ref mut data.items as scratch: Vec<i32>);
+    scratch.clear();
+    assert!(scratch.is_empty());
+    assert_eq!(data.items, [1, 2]);
+}
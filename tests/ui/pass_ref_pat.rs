@@ -0,0 +1,36 @@
+// Test: `ref_pat` binding the clone with a `ref` pattern
+use letclone::clone;
+
+struct Config {
+    name: String,
+}
+
+fn takes_ref(name: &String) -> usize {
+    name.len()
+}
+
+fn main() {
+    let config = Config {
+        name: String::from("app"),
+    };
+
+    // Equivalent to: let ref name = config.name.clone();
+    clone!(ref_pat config.name);
+    let reference: &String = name;
+    assert_eq!(takes_ref(name), 3);
+    assert_eq!(reference, "app");
+
+    // The clone lives until the end of the block
+    let len = {
+        clone!(ref_pat config.name as other);
+        other.len()
+    };
+    assert_eq!(len, 3);
+
+    // `mut ref_pat` binds a mutable reference to the clone
+    clone!(mut ref_pat config.name as edited);
+    let edited: &mut String = edited;
+    edited.push('!');
+    assert_eq!(edited, "app!");
+    assert_eq!(config.name, "app");
+}
@@ -0,0 +1,41 @@
+// Test: cloning bindings introduced by `ref` patterns yields owned values
+use letclone::clone;
+
+struct Request {
+    user: Option<String>,
+    tags: Vec<String>,
+}
+
+fn main() {
+    let request = Request {
+        user: Some(String::from("alice")),
+        tags: vec![String::from("a")],
+    };
+
+    match request.user {
+        Some(ref user) => {
+            // `user: &String`; method resolution picks `String::clone`
+            clone!(user);
+            let owned: String = user;
+            assert_eq!(owned, "alice");
+        }
+        None => unreachable!(),
+    }
+
+    // The same holds for match ergonomics and field access through references
+    if let Request { tags, .. } = &request {
+        clone!(tags as owned_tags);
+        let owned_tags: Vec<String> = owned_tags;
+        assert_eq!(owned_tags, ["a"]);
+    }
+
+    // `deref` makes the intent explicit and fails to compile for types that
+    // are not `Clone`, instead of silently copying the reference
+    if let Some(ref user) = request.user {
+        clone!(deref user as explicit);
+        let explicit: String = explicit;
+        assert_eq!(explicit, "alice");
+    }
+
+    assert_eq!(request.user.as_deref(), Some("alice"));
+}
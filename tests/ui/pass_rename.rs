@@ -0,0 +1,20 @@
+// Test: clone! with an `as name` rename
+use letclone::clone;
+
+struct Person {
+    name: String,
+}
+
+fn main() {
+    let person = Person {
+        name: String::from("Alice"),
+    };
+    let tuple = (1, String::from("second"));
+    let into_owned = String::from("not a modifier");
+
+    clone!(person.name as person_name, tuple.1 as second, mut into_owned as text);
+    text.push('!');
+    assert_eq!(person_name, "Alice");
+    assert_eq!(second, "second");
+    assert_eq!(text, "not a modifier!");
+}
@@ -0,0 +1,32 @@
+// Test: clone! accepts any expression when the binding is renamed
+use letclone::clone;
+
+fn make() -> String {
+    String::from("made")
+}
+
+fn main() {
+    let a = String::from("a");
+    let b = "b";
+    let v = vec![String::from("first"), String::from("second")];
+    let x = 300u32;
+    let n = 5i32;
+
+    clone!(a + b as sum, v[0] as first, make() as r);
+    // Equivalent to:
+    // let sum = (a + b).clone();
+    // let first = v[0].clone();
+    // let r = make().clone();
+    assert_eq!(sum, "ab");
+    assert_eq!(first, "first");
+    assert_eq!(r, "made");
+
+    let a = String::from("a");
+    clone!(mut x as u8 as small, -n as negated, v[1] as second, n * 2 + 1 as odd);
+    small += 1;
+    assert_eq!(small, 45);
+    assert_eq!(negated, -5);
+    assert_eq!(second, "second");
+    assert_eq!(odd, 11);
+    assert_eq!(a, "a");
+}
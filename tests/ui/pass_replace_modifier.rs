@@ -0,0 +1,32 @@
+// Test: `replace` takes a field's value and leaves the default behind
+use letclone::clone;
+
+struct Slot {
+    value: Vec<u32>,
+    label: String,
+}
+
+fn drain(slot: &mut Slot) -> Vec<u32> {
+    // Equivalent to: let old = std::mem::replace(&mut slot.value, Default::default());
+    clone!(replace slot.value as old);
+    old
+}
+
+fn main() {
+    let mut slot = Slot {
+        value: vec![1, 2, 3],
+        label: String::from("slot"),
+    };
+
+    assert_eq!(drain(&mut slot), [1, 2, 3]);
+    assert!(slot.value.is_empty());
+
+    // Combines with clones of other fields in the same invocation
+    slot.value.push(4);
+    clone!(slot.label, mut replace slot.value as old);
+    old.push(5);
+    assert_eq!(old, [4, 5]);
+    assert!(slot.value.is_empty());
+    assert_eq!(label, "slot");
+    assert_eq!(slot.label, "slot");
+}
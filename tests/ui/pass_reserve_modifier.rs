@@ -0,0 +1,29 @@
+// Test: `reserve(n)` modifier reserving extra capacity in the clone
+use letclone::clone;
+
+struct Data {
+    items: Vec<u32>,
+}
+
+fn main() {
+    let data = Data {
+        items: vec![1, 2, 3],
+    };
+
+    // Equivalent to: let mut items = data.items.clone(); items.reserve(16);
+    clone!(reserve(16) data.items as items);
+    assert!(items.capacity() >= 3 + 16);
+    items.push(4);
+    assert_eq!(items, [1, 2, 3, 4]);
+    assert_eq!(data.items, [1, 2, 3]);
+
+    // The argument can be any expression, and the name can be derived
+    let extra = 8;
+    clone!(reserve(extra * 2) data.items);
+    assert!(items.capacity() >= 3 + 16);
+
+    // A variable named `reserve` is still cloned as usual
+    let reserve = String::from("reserve");
+    clone!(reserve);
+    assert_eq!(reserve, "reserve");
+}
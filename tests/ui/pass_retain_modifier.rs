@@ -0,0 +1,34 @@
+// Test: `retain(predicate)` filters the clone in place
+use letclone::clone;
+use std::collections::HashMap;
+
+struct Data {
+    nums: Vec<i32>,
+    text: String,
+}
+
+fn main() {
+    let data = Data {
+        nums: vec![-2, 3, 0, 5, -1],
+        text: String::from("a1b2"),
+    };
+
+    // Equivalent to: let mut nums = data.nums.clone(); nums.retain(|x| *x > 0);
+    clone!(retain(|x| *x > 0) data.nums as nums);
+    assert_eq!(nums, [3, 5]);
+    assert_eq!(data.nums, [-2, 3, 0, 5, -1]);
+
+    // The predicate can capture variables and use any closure form
+    let limit = 4;
+    clone!(retain(|&x| x < limit) data.nums as small, retain(char::is_alphabetic) data.text as letters);
+    assert_eq!(small, [-2, 3, 0, -1]);
+    assert_eq!(letters, "ab");
+
+    // Runs in written order with the other post-operations
+    clone!(retain(|x| *x != 0) sorted reverse data.nums as ordered);
+    assert_eq!(ordered, [5, 3, -1, -2]);
+
+    let scores = HashMap::from([("a", 1), ("b", 7)]);
+    clone!(retain(|_, score| *score > 5) scores as high);
+    assert_eq!(high.len(), 1);
+}
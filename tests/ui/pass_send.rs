@@ -0,0 +1,20 @@
+// Test: `send` directive accepting clones that can be moved to another thread
+use letclone::clone;
+use std::sync::{Arc, Mutex};
+
+fn main() {
+    let state = Arc::new(Mutex::new(vec![1, 2, 3]));
+    let label = String::from("worker");
+
+    clone!(send; state, label as name);
+    let handle = std::thread::spawn(move || {
+        state.lock().unwrap().push(4);
+        name
+    });
+    assert_eq!(handle.join().unwrap(), "worker");
+    assert_eq!(label, "worker");
+
+    clone!(send mut; ref mut label as edited);
+    edited.push('!');
+    assert_eq!(edited, "worker!");
+}
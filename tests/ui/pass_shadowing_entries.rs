@@ -0,0 +1,35 @@
+// Test: how entries of one invocation interact when names collide
+#![deny(deprecated)]
+use letclone::clone;
+
+#[derive(Clone)]
+struct Config {
+    name: String,
+    port: u16,
+}
+
+fn main() {
+    let config = Config { name: String::from("app"), port: 8080 };
+
+    // A plain rebinding is not reported: the later entry clones an equal value
+    clone!(config, config.port as port);
+    assert_eq!(port, 8080);
+
+    // Entries only see bindings introduced before them, so an earlier entry
+    // reads the original even when a later one reuses its name
+    let name = String::from("original");
+    clone!(name as before, config.name as name);
+    assert_eq!(before, "original");
+    assert_eq!(name, "app");
+
+    // A later entry reading a new name uses the earlier clone, as intended
+    clone!(config.name as label, label.len() as len);
+    assert_eq!(len, label.len());
+
+    // A later entry reading a name that replaced a variable clones the new
+    // binding; the warning can be silenced per entry when this is intended
+    let value = 1u16;
+    clone!(value + config.port as value, #[allow(deprecated)] value as total);
+    assert_eq!(total, 8081);
+    assert_eq!(value, 8081);
+}
@@ -0,0 +1,49 @@
+// Test: `shared!` creates `Rc<RefCell<_>>` handles and `share_clone!` shares them
+use letclone::{share_clone, shared};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+struct Node {
+    children: Rc<RefCell<Vec<u32>>>,
+}
+
+struct Template {
+    values: Vec<u32>,
+    label: String,
+}
+
+fn main() {
+    let template = Template {
+        values: vec![1],
+        label: String::from("root"),
+    };
+
+    // Equivalent to: let values = Rc::new(RefCell::new(template.values.clone()));
+    shared!(template.values, template.label as label);
+    let _: &Rc<RefCell<Vec<u32>>> = &values;
+    label.borrow_mut().push_str("!");
+    assert_eq!(*label.borrow(), "root!");
+    assert_eq!(template.label, "root");
+
+    // Clone the handle into another scope and mutate through both
+    {
+        share_clone!(values as other);
+        other.borrow_mut().push(2);
+        assert_eq!(Rc::strong_count(&values), 2);
+    }
+    values.borrow_mut().push(3);
+    assert_eq!(*values.borrow(), [1, 2, 3]);
+    assert_eq!(Rc::strong_count(&values), 1);
+
+    // Field sources and closures owning a shared handle
+    let node = Node {
+        children: values.clone(),
+    };
+    let push = {
+        share_clone!(node.children);
+        move |child| children.borrow_mut().push(child)
+    };
+    push(4);
+    assert_eq!(*values.borrow(), [1, 2, 3, 4]);
+    assert!(Rc::ptr_eq(&node.children, &values));
+}
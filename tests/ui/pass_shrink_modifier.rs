@@ -0,0 +1,31 @@
+// Test: `shrink` modifier releases spare capacity of the clone
+use letclone::clone;
+use std::collections::HashMap;
+
+struct Cache {
+    entries: HashMap<u32, String>,
+    buffer: Vec<u8>,
+}
+
+fn main() {
+    let mut buffer = Vec::with_capacity(64);
+    buffer.extend_from_slice(&[1, 1, 2, 2, 3]);
+    let mut entries = HashMap::with_capacity(128);
+    entries.insert(1, String::from("one"));
+    let cache = Cache { entries, buffer };
+
+    // Equivalent to: let mut c = cache.buffer.clone(); c.shrink_to_fit();
+    clone!(shrink cache.buffer as c);
+    assert_eq!(c.capacity(), 5);
+    c.push(4);
+
+    // Runs after the other post-operations, in written order
+    clone!(dedup shrink cache.buffer as unique);
+    assert_eq!(unique, [1, 2, 3]);
+    assert_eq!(unique.capacity(), 3);
+
+    clone!(shrink cache.entries);
+    assert!(entries.capacity() < 128);
+    assert_eq!(entries[&1], "one");
+    assert_eq!(cache.buffer.capacity(), 64);
+}
@@ -0,0 +1,34 @@
+// Test: `sorted` and `sorted_by(..)` modifiers sort the clone
+use letclone::clone;
+
+struct Data {
+    items: Vec<i32>,
+    names: Vec<String>,
+}
+
+fn main() {
+    let data = Data {
+        items: vec![3, 1, 2],
+        names: vec![String::from("bob"), String::from("Al"), String::from("cy")],
+    };
+
+    // Equivalent to: let mut items = data.items.clone(); items.sort();
+    clone!(sorted data.items as items);
+    assert_eq!(items, [1, 2, 3]);
+    // The source is untouched and the binding is mutable
+    assert_eq!(data.items, [3, 1, 2]);
+    items.push(0);
+
+    clone!(sorted_by(|a, b| b.cmp(a)) data.items as descending);
+    assert_eq!(descending, [3, 2, 1]);
+
+    let by_len = |a: &String, b: &String| a.len().cmp(&b.len()).then(a.cmp(b));
+    clone!(sorted_by(by_len) data.names, sorted data.names as alphabetical);
+    assert_eq!(names, ["Al", "cy", "bob"]);
+    assert_eq!(alphabetical, ["Al", "bob", "cy"]);
+
+    // Composes with other modifiers
+    clone!(sorted reserve(4) data.items as reserved);
+    assert!(reserved.capacity() >= 7);
+    assert_eq!(reserved, [1, 2, 3]);
+}
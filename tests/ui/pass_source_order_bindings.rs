@@ -0,0 +1,31 @@
+// Test: later entries can use the bindings of earlier entries
+use letclone::clone;
+
+struct Config {
+    name: String,
+    retries: u32,
+}
+
+fn main() {
+    let config = Config {
+        name: String::from("app"),
+        retries: 3,
+    };
+
+    // `name` in the second entry is the clone made by the first
+    clone!(config.name, name.to_uppercase() as upper, mut config.retries, retries * 2 as doubled);
+    assert_eq!(upper, "APP");
+    assert_eq!(doubled, 6);
+    retries += 1;
+    assert_eq!(retries, 4);
+
+    // Earlier bindings shadow the originals for the rest of the invocation;
+    // reading a replaced variable again is reported unless allowed
+    let value = 1;
+    {
+        clone!(value + 1 as value, #[allow(deprecated)] value + 1 as next);
+        assert_eq!(value, 2);
+        assert_eq!(next, 3);
+    }
+    assert_eq!(value, 1);
+}
@@ -0,0 +1,52 @@
+// Test: `spawn_clone!` clones values and spawns a thread using the clones
+use letclone::spawn_clone;
+use std::sync::{Arc, Mutex};
+
+struct Shared {
+    hits: Arc<Mutex<u32>>,
+    log: Arc<Mutex<Vec<String>>>,
+}
+
+fn main() {
+    let hits = Arc::new(Mutex::new(0));
+    let log = Arc::new(Mutex::new(Vec::new()));
+
+    // Equivalent to:
+    // { let hits = hits.clone(); let log = log.clone(); std::thread::spawn(move || { ... }) }
+    let handle = spawn_clone!(hits, log => {
+        *hits.lock().unwrap() += 1;
+        log.lock().unwrap().push(String::from("worker"));
+    });
+    handle.join().unwrap();
+
+    // The originals stay usable on the spawning thread
+    *hits.lock().unwrap() += 1;
+    assert_eq!(*hits.lock().unwrap(), 2);
+    assert_eq!(Arc::strong_count(&hits), 1);
+
+    // Renames, field sources and the body's value through the handle
+    let shared = Shared {
+        hits: hits.clone(),
+        log: log.clone(),
+    };
+    let handles: Vec<_> = (0..4)
+        .map(|i| {
+            spawn_clone!(shared.hits as counter, shared.log => {
+                *counter.lock().unwrap() += 1;
+                log.lock().unwrap().push(format!("thread {i}"));
+                i * 10
+            })
+        })
+        .collect();
+    let results: Vec<u32> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+    assert_eq!(results, [0, 10, 20, 30]);
+    assert_eq!(*shared.hits.lock().unwrap(), 6);
+    assert_eq!(log.lock().unwrap().len(), 5);
+
+    // Attributes applied with `=>` do not end the entries
+    let handle = spawn_clone!(#[allow(unused_mut)] => mut hits => {
+        *hits.lock().unwrap() = 0;
+    });
+    handle.join().unwrap();
+    assert_eq!(*hits.lock().unwrap(), 0);
+}
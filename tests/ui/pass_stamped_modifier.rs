@@ -0,0 +1,27 @@
+// Test: `stamped` pairs the clone with the instant it was made
+use letclone::clone;
+use std::time::{Duration, Instant};
+
+struct Cache {
+    data: Vec<u32>,
+}
+
+fn main() {
+    let cache = Cache { data: vec![1, 2, 3] };
+    let before = Instant::now();
+
+    // Equivalent to: let entry = (cache.data.clone(), std::time::Instant::now());
+    clone!(stamped cache.data as entry);
+    let (data, stamp): (Vec<u32>, Instant) = entry;
+    assert_eq!(data, cache.data);
+    assert!(stamp >= before);
+    assert!(stamp.elapsed() < Duration::from_secs(60));
+
+    // Composes with other modifiers, which apply to the clone first
+    let name = String::from("key");
+    clone!(wrap(Box) name as boxed, mut stamped name as stamped);
+    assert_eq!(*boxed, "key");
+    stamped.0.push('s');
+    assert_eq!(stamped.0, "keys");
+    assert_eq!(name, "key");
+}
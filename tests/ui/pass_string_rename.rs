@@ -0,0 +1,39 @@
+// Test: renaming with a string literal
+use letclone::clone;
+
+struct Source {
+    value: String,
+    count: u32,
+}
+
+macro_rules! clone_named {
+    ($source:expr, $name:literal) => {
+        clone!($source as $name);
+    };
+}
+
+fn main() {
+    let source = Source {
+        value: String::from("hello"),
+        count: 2,
+    };
+
+    // Equivalent to: let custom_name = source.value.clone();
+    clone!(source.value as "custom_name");
+    assert_eq!(custom_name, "hello");
+
+    // Combines with `mut`, type annotations, `via` and other entries
+    clone!(mut source.count as "total": u32, source.value via to_uppercase as "upper", source.count);
+    total += count;
+    assert_eq!(total, 4);
+    assert_eq!(upper, "HELLO");
+
+    // Raw identifiers are accepted
+    clone!(source.value as "r#type");
+    assert_eq!(r#type, "hello");
+
+    // Macro authors can pass computed names through
+    let name = String::from("macro");
+    clone_named!(name, "from_macro");
+    assert_eq!(from_macro, "macro");
+}
@@ -0,0 +1,33 @@
+// Test: `suffix_index` directive appends each entry's position to its name
+use letclone::clone;
+
+struct Item {
+    name: String,
+}
+
+macro_rules! clone_items {
+    ($($item:expr),*) => {
+        clone!(suffix_index; $($item),*);
+    };
+}
+
+fn main() {
+    let a = String::from("a");
+    let item = Item {
+        name: String::from("item"),
+    };
+
+    // Equivalent to: let a_0 = a.clone(); let name_1 = item.name.clone(); ...
+    clone!(suffix_index; a, item.name, mut a as r#ref);
+    ref_2.push('!');
+    assert_eq!((a_0.as_str(), name_1.as_str(), ref_2.as_str()), ("a", "item", "a!"));
+
+    // Applied after `prefix`
+    clone!(prefix = "old_" suffix_index; a, item.name);
+    assert_eq!((old_a_0.as_str(), old_name_1.as_str()), ("a", "item"));
+
+    // From another macro
+    clone_items!(a, item.name);
+    assert_eq!(a_0, "a");
+    assert_eq!(name_1, "item");
+}
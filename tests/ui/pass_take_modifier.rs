@@ -0,0 +1,28 @@
+// Test: clone! borrows the source while the take modifier moves it
+use letclone::clone;
+
+struct Data {
+    name: String,
+    tags: Vec<String>,
+}
+
+fn main() {
+    let data = Data {
+        name: String::from("report"),
+        tags: vec![String::from("draft")],
+    };
+
+    // Cloning borrows: `data` stays fully usable
+    clone!(data.name as copy);
+    assert_eq!(copy, "report");
+    assert_eq!(data.name, "report");
+
+    // Taking moves the field out: the remaining fields are still usable
+    clone!(take data.name as name);
+    assert_eq!(name, "report");
+    assert_eq!(data.tags, vec![String::from("draft")]);
+
+    clone!(mut take data.tags);
+    tags.push(String::from("final"));
+    assert_eq!(tags.len(), 2);
+}
@@ -0,0 +1,34 @@
+// Test: `timed` modifier reporting slow clones under debug assertions
+use letclone::clone;
+use std::time::Duration;
+
+struct Slow(Vec<u8>);
+
+impl Clone for Slow {
+    fn clone(&self) -> Self {
+        std::thread::sleep(Duration::from_millis(2));
+        Slow(self.0.clone())
+    }
+}
+
+struct Big {
+    data: Vec<u64>,
+    slow: Slow,
+}
+
+fn main() {
+    let big = Big {
+        data: (0..1000).collect(),
+        slow: Slow(vec![1, 2, 3]),
+    };
+
+    // The value is the plain clone; only the timing is added around it
+    clone!(timed big.data as data);
+    assert_eq!(data, big.data);
+
+    // Slow clones are reported on stderr and still produce the value
+    clone!(timed big.slow, mut timed big.data);
+    assert_eq!(slow.0, [1, 2, 3]);
+    data.push(1000);
+    assert_eq!(data.len(), 1001);
+}
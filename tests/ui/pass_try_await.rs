@@ -0,0 +1,68 @@
+// Test: clone! with `?` and `.await` on method-call sources
+use letclone::clone;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Record {
+    id: u32,
+}
+
+struct Repo {
+    records: Vec<Record>,
+}
+
+impl Repo {
+    async fn fetch(&self, id: u32) -> Result<&Record, String> {
+        self.records
+            .iter()
+            .find(|record| record.id == id)
+            .ok_or_else(|| format!("record {} not found", id))
+    }
+
+    fn lookup(&self, id: u32) -> Option<&Record> {
+        self.records.iter().find(|record| record.id == id)
+    }
+}
+
+async fn load(repo: &Repo, id: u32) -> Result<Record, String> {
+    clone!(repo.fetch(id).await? as record);
+    // Equivalent to: let record = repo.fetch(id).await?.clone();
+    let record: Record = record;
+    Ok(record)
+}
+
+async fn load_default_name(repo: &Repo) -> Result<u32, String> {
+    clone!(repo.fetch(1).await?);
+    Ok(fetch.id)
+}
+
+fn first_id(repo: &Repo) -> Option<u32> {
+    clone!(repo.lookup(1)?);
+    Some(lookup.id)
+}
+
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut context = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+fn main() {
+    let repo = Repo {
+        records: vec![Record { id: 1 }],
+    };
+
+    assert_eq!(block_on(load(&repo, 1)), Ok(Record { id: 1 }));
+    assert_eq!(
+        block_on(load(&repo, 2)),
+        Err(String::from("record 2 not found"))
+    );
+    assert_eq!(block_on(load_default_name(&repo)), Ok(1));
+    assert_eq!(first_id(&repo), Some(1));
+}
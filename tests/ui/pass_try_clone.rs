@@ -0,0 +1,43 @@
+// Test: try_clone! cloning through a fallible `try_clone` method
+use letclone::try_clone;
+
+#[derive(Debug, PartialEq)]
+struct Handle {
+    id: u32,
+}
+
+#[derive(Debug, PartialEq)]
+struct Exhausted;
+
+impl Handle {
+    fn try_clone(&self) -> Result<Handle, Exhausted> {
+        if self.id == 0 {
+            Err(Exhausted)
+        } else {
+            Ok(Handle { id: self.id })
+        }
+    }
+}
+
+struct Log {
+    file: Handle,
+}
+
+fn duplicate(log: &Log, spare: &Handle) -> Result<(Handle, Handle), Exhausted> {
+    // Equivalent to:
+    // let f = log.file.try_clone()?;
+    // let spare = spare.try_clone()?;
+    try_clone!(log.file as f, spare);
+    Ok((f, spare))
+}
+
+fn main() {
+    let log = Log {
+        file: Handle { id: 1 },
+    };
+    let (f, spare) = duplicate(&log, &Handle { id: 2 }).unwrap();
+    assert_eq!((f.id, spare.id), (1, 2));
+
+    // The error is propagated with `?`
+    assert_eq!(duplicate(&log, &Handle { id: 0 }), Err(Exhausted));
+}
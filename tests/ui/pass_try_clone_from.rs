@@ -0,0 +1,34 @@
+use letclone::try_clone_from;
+
+#[derive(Debug, PartialEq)]
+struct Parsed(u32);
+
+impl TryFrom<String> for Parsed {
+    type Error = std::num::ParseIntError;
+
+    fn try_from(raw: String) -> Result<Self, Self::Error> {
+        raw.parse().map(Parsed)
+    }
+}
+
+fn parse(raw: &String) -> Result<Parsed, std::num::ParseIntError> {
+    try_clone_from!(raw: Parsed);
+    Ok(raw)
+}
+
+fn parse_boxed(raw: &String) -> Result<Parsed, Box<dyn std::error::Error>> {
+    // The conversion error is converted by `?` like any other
+    try_clone_from!(raw as parsed: Parsed);
+    Ok(parsed)
+}
+
+fn main() {
+    let raw = String::from("42");
+    assert_eq!(parse(&raw), Ok(Parsed(42)));
+    assert_eq!(parse_boxed(&raw).unwrap(), Parsed(42));
+    assert_eq!(raw, "42");
+
+    let bad = String::from("forty-two");
+    assert!(parse(&bad).is_err());
+    assert!(parse_boxed(&bad).is_err());
+}
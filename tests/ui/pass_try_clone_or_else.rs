@@ -0,0 +1,68 @@
+// Test: `or_else { ... }` handles the error of `try_clone!` instead of `?`
+use letclone::try_clone;
+
+struct Handle {
+    id: u32,
+    broken: bool,
+}
+
+impl Handle {
+    fn try_clone(&self) -> Result<Handle, String> {
+        if self.broken {
+            Err(format!("handle {} is broken", self.id))
+        } else {
+            Ok(Handle {
+                id: self.id,
+                broken: false,
+            })
+        }
+    }
+}
+
+struct Log {
+    handle: Handle,
+}
+
+fn duplicate(handle: &Handle) -> Result<Option<u32>, String> {
+    // Equivalent to:
+    // let copy = match handle.try_clone() { Ok(v) => v, Err(_) => { return Ok(None) } };
+    try_clone!(handle or_else { return Ok(None) } as copy);
+    Ok(Some(copy.id))
+}
+
+fn first_working(handles: &[Handle]) -> Option<u32> {
+    for handle in handles {
+        try_clone!(handle or_else { continue } as copy);
+        return Some(copy.id);
+    }
+    None
+}
+
+fn main() {
+    let working = Handle {
+        id: 1,
+        broken: false,
+    };
+    let broken = Handle {
+        id: 2,
+        broken: true,
+    };
+
+    // Success path
+    assert_eq!(duplicate(&working), Ok(Some(1)));
+    // Error path runs the block
+    assert_eq!(duplicate(&broken), Ok(None));
+    assert_eq!(first_working(&[broken, working]), Some(1));
+
+    // A block evaluating to a fallback value, in a function without `Result`
+    let log = Log {
+        handle: Handle {
+            id: 3,
+            broken: true,
+        },
+    };
+    try_clone!(mut log.handle or_else { Handle { id: 0, broken: false } } as fallback);
+    fallback.id += 10;
+    assert_eq!(fallback.id, 10);
+    assert_eq!(log.handle.id, 3);
+}
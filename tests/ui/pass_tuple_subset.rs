@@ -0,0 +1,58 @@
+// Test: cloning a subset of tuple struct fields with gaps
+use letclone::clone;
+use std::cell::Cell;
+
+#[derive(Debug, PartialEq)]
+struct Record(u32, String, u32, String, Vec<u8>);
+
+fn main() {
+    let record = Record(
+        1,
+        String::from("Alice"),
+        30,
+        String::from("alice@example.com"),
+        vec![1, 2],
+    );
+
+    // Arbitrary indices with renames, skipping the others
+    clone!(record.1 as name, record.3 as email);
+    assert_eq!(name, "Alice");
+    assert_eq!(email, "alice@example.com");
+    assert_eq!(
+        record,
+        Record(
+            1,
+            String::from("Alice"),
+            30,
+            String::from("alice@example.com"),
+            vec![1, 2],
+        )
+    );
+
+    // Without renames, the names come from the indices
+    clone!(record.4, mut record.2);
+    field_2 += 1;
+    assert_eq!(field_4, [1, 2]);
+    assert_eq!(field_2, 31);
+
+    // A computed base written in several entries is evaluated by each
+    let loads = Cell::new(0);
+    let load = || {
+        loads.set(loads.get() + 1);
+        Record(2, String::from("Bob"), 40, String::from("bob@example.com"), vec![])
+    };
+    clone!(load().1 as other_name, load().3 as other_email);
+    assert_eq!(loads.get(), 2);
+    assert_eq!(other_name, "Bob");
+    assert_eq!(other_email, "bob@example.com");
+
+    // `hoist` evaluates it once
+    clone!(hoist; load().1 as hoisted_name, load().3 as hoisted_email);
+    assert_eq!(loads.get(), 3);
+    assert_eq!((hoisted_name.as_str(), hoisted_email.as_str()), ("Bob", "bob@example.com"));
+
+    // Different bases are evaluated separately
+    clone!(hoist; load().1 as a, (load()).3 as b);
+    assert_eq!(loads.get(), 5);
+    assert_eq!((a.as_str(), b.as_str()), ("Bob", "bob@example.com"));
+}
@@ -0,0 +1,19 @@
+// Test: clone! with a type annotation on the binding
+use letclone::clone;
+use std::sync::Arc;
+
+struct Data {
+    name: String,
+}
+
+fn main() {
+    let data = Data {
+        name: String::from("text"),
+    };
+    let shared = Arc::new(5);
+
+    clone!(data.name: String, shared as handle: Arc<i32>);
+    assert_eq!(name, "text");
+    assert_eq!(*handle, 5);
+    assert_eq!(Arc::strong_count(&shared), 2);
+}
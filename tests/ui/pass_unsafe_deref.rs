@@ -0,0 +1,28 @@
+// Test: clone! with `unsafe deref` on raw pointers
+use letclone::clone;
+
+struct Handle {
+    name: *const String,
+}
+
+fn main() {
+    let owned = String::from("ffi value");
+    let ptr: *const String = &owned;
+    let handle = Handle { name: ptr };
+
+    // SAFETY: `ptr` points to `owned`, which is alive and not mutated
+    clone!(unsafe deref ptr as value, mut unsafe deref handle.name as name);
+    // Equivalent to:
+    // let value = unsafe { (*ptr).clone() };
+    // let mut name = unsafe { (*handle.name).clone() };
+    let value: String = value;
+    name.push_str(" copy");
+    assert_eq!(value, "ffi value");
+    assert_eq!(name, "ffi value copy");
+    assert_eq!(owned, "ffi value");
+
+    // Unsafe blocks are still ordinary sources
+    let block = unsafe { &*ptr };
+    clone!(unsafe { block.len() } as len);
+    assert_eq!(len, 9);
+}
@@ -0,0 +1,52 @@
+// Test: `upcast` clones a trait object pointer into a supertrait object
+use letclone::clone;
+use std::rc::Rc;
+use std::sync::Arc;
+
+trait Base {
+    fn name(&self) -> String;
+}
+
+trait Sub: Base {
+    fn extra(&self) -> u32;
+}
+
+struct Widget;
+
+impl Base for Widget {
+    fn name(&self) -> String {
+        String::from("widget")
+    }
+}
+
+impl Sub for Widget {
+    fn extra(&self) -> u32 {
+        7
+    }
+}
+
+struct Registry {
+    handle: Arc<dyn Sub>,
+}
+
+fn main() {
+    let handle: Arc<dyn Sub> = Arc::new(Widget);
+
+    // Equivalent to: let base: Arc<dyn Base> = handle.clone();
+    clone!(upcast handle as base: Arc<dyn Base>);
+    assert_eq!(base.name(), "widget");
+    assert_eq!(handle.extra(), 7);
+    assert_eq!(Arc::strong_count(&handle), 2);
+
+    // Field sources, other pointers and adding auto traits away
+    let registry = Registry { handle };
+    clone!(upcast registry.handle as shared: Arc<dyn Base>);
+    assert_eq!(shared.name(), "widget");
+    let local: Rc<dyn Sub + Send> = Rc::new(Widget);
+    clone!(upcast local as plain: Rc<dyn Base>);
+    assert_eq!(plain.name(), "widget");
+
+    // The annotation alone also coerces; `upcast` documents the intent
+    clone!(registry.handle as annotated: Arc<dyn Base>);
+    assert_eq!(annotated.name(), "widget");
+}
@@ -0,0 +1,64 @@
+// Test: `using <path>` directive calls a free function with `&source`
+use letclone::clone;
+
+mod dyn_clone {
+    pub trait DynClone {
+        fn clone_box(&self) -> Box<dyn Widget>;
+    }
+
+    pub trait Widget: DynClone {
+        fn name(&self) -> String;
+    }
+
+    impl<T: Widget + Clone + 'static> DynClone for T {
+        fn clone_box(&self) -> Box<dyn Widget> {
+            Box::new(self.clone())
+        }
+    }
+
+    pub fn clone_box<T: ?Sized + DynClone>(value: &T) -> Box<dyn Widget> {
+        value.clone_box()
+    }
+}
+
+use dyn_clone::Widget;
+
+#[derive(Clone)]
+struct Button {
+    label: String,
+}
+
+impl Widget for Button {
+    fn name(&self) -> String {
+        self.label.clone()
+    }
+}
+
+struct Holder {
+    widget: Box<dyn Widget>,
+}
+
+fn main() {
+    let widget = Button {
+        label: String::from("ok"),
+    };
+
+    // Equivalent to: let w = dyn_clone::clone_box(&widget);
+    clone!(using dyn_clone::clone_box; widget as w);
+    assert_eq!(w.name(), "ok");
+    assert_eq!(widget.label, "ok");
+
+    // Works with field sources and several entries
+    let holder = Holder {
+        widget: Box::new(Button {
+            label: String::from("cancel"),
+        }),
+    };
+    clone!(using dyn_clone::clone_box; *holder.widget as first, widget as second);
+    assert_eq!(first.name(), "cancel");
+    assert_eq!(second.name(), "ok");
+
+    // An entry's own `via` takes precedence
+    clone!(using dyn_clone::clone_box; widget.label via to_uppercase as shout);
+    assert_eq!(shout, "OK");
+}
@@ -0,0 +1,51 @@
+// Test: clone! with `via` on method-call and function-call sources
+use letclone::clone;
+
+// Deliberately not `Clone`: the enum provides its own snapshot method
+#[derive(Debug, PartialEq)]
+enum State {
+    Idle,
+    Running { step: u32 },
+}
+
+impl State {
+    fn snapshot(&self) -> State {
+        match self {
+            State::Idle => State::Idle,
+            State::Running { step } => State::Running { step: *step },
+        }
+    }
+}
+
+struct Machine {
+    state: State,
+}
+
+impl Machine {
+    fn current(&self) -> &State {
+        &self.state
+    }
+}
+
+fn idle_state() -> State {
+    State::Idle
+}
+
+fn main() {
+    let machine = Machine {
+        state: State::Running { step: 2 },
+    };
+
+    clone!(machine.current() via snapshot as snap);
+    // Equivalent to: let snap = machine.current().snapshot();
+    assert_eq!(snap, State::Running { step: 2 });
+
+    clone!(machine.state via snapshot, idle_state() via snapshot as idle);
+    assert_eq!(state, State::Running { step: 2 });
+    assert_eq!(idle, State::Idle);
+
+    clone!(mut machine.state via snapshot as next);
+    next = State::Idle;
+    assert_eq!(next, State::Idle);
+    assert_eq!(machine.state, State::Running { step: 2 });
+}
@@ -0,0 +1,60 @@
+// Test: `via <Trait>::method` disambiguating between traits
+use letclone::clone;
+
+struct State {
+    version: u32,
+}
+
+trait StateExt {
+    fn snapshot(&self) -> u32;
+}
+
+trait DebugExt {
+    fn snapshot(&self) -> String;
+}
+
+impl StateExt for State {
+    fn snapshot(&self) -> u32 {
+        self.version
+    }
+}
+
+impl DebugExt for State {
+    fn snapshot(&self) -> String {
+        format!("State v{}", self.version)
+    }
+}
+
+trait Convert<T> {
+    fn snapshot(&self) -> T;
+}
+
+impl Convert<i64> for State {
+    fn snapshot(&self) -> i64 {
+        -(self.version as i64)
+    }
+}
+
+struct Machine {
+    state: State,
+}
+
+fn main() {
+    let machine = Machine {
+        state: State { version: 7 },
+    };
+
+    // Equivalent to: let version = StateExt::snapshot(&machine.state);
+    clone!(machine.state via <StateExt>::snapshot as version);
+    assert_eq!(version, 7);
+
+    clone!(machine.state via <DebugExt>::snapshot as description);
+    assert_eq!(description, "State v7");
+
+    // Generic traits and `mut` bindings
+    let state = State { version: 7 };
+    clone!(state via <Convert<i64>>::snapshot as negated, mut state via <StateExt>::snapshot as v);
+    assert_eq!(negated, -7);
+    v += 1;
+    assert_eq!(v, 8);
+}
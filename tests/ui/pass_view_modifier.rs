@@ -0,0 +1,40 @@
+// Test: `view` modifier binds a borrowed view of a clone kept alive in scope
+use letclone::clone;
+
+struct Owned {
+    text: String,
+    items: Vec<u32>,
+}
+
+fn takes_str(s: &str) -> usize {
+    s.len()
+}
+
+fn main() {
+    let mut owned = Owned {
+        text: String::from("hello"),
+        items: vec![1, 2, 3],
+    };
+
+    // Equivalent to: let __text = owned.text.clone(); let text = &*__text;
+    clone!(view owned.text as text, view owned.items);
+    let text: &str = text;
+    let items: &[u32] = items;
+
+    // The original can change while the views stay valid
+    owned.text.push_str(" world");
+    owned.items.clear();
+    assert_eq!(text, "hello");
+    assert_eq!(items, [1, 2, 3]);
+    assert_eq!(takes_str(text), 5);
+
+    // The view is usable for the rest of the scope
+    let words: Vec<&str> = text.split('l').collect();
+    assert_eq!(words, ["he", "", "o"]);
+
+    // `mut` makes the view rebindable; `reserve` applies to the hidden clone
+    clone!(mut view reserve(8) owned.text as current);
+    assert_eq!(current, "hello world");
+    current = &current[..5];
+    assert_eq!(current, "hello");
+}
@@ -0,0 +1,15 @@
+// Test: `warn("...")` only warns, and can be allowed
+#![allow(deprecated)]
+#![deny(unused_variables)]
+use letclone::clone;
+
+struct Config {
+    data: Vec<u8>,
+}
+
+fn main() {
+    let config = Config { data: vec![1, 2] };
+    clone!(warn("share `Config` with an `Arc`"); config.data, mut config.data as extra);
+    extra.push(3);
+    assert_eq!(data.len() + extra.len(), 5);
+}
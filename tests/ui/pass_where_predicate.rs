@@ -0,0 +1,76 @@
+// Test: `where <predicate>` post-conditions checked with debug_assert!
+use letclone::clone;
+
+#[derive(Clone)]
+struct Config {
+    retries: u32,
+}
+
+impl Config {
+    fn is_valid(&self) -> bool {
+        self.retries > 0
+    }
+}
+
+struct App {
+    config: Config,
+    name: String,
+}
+
+fn main() {
+    let app = App {
+        config: Config { retries: 3 },
+        name: String::from("app"),
+    };
+
+    // Closures with inferred parameter types
+    clone!(app.config where |c| c.is_valid(), app.name where |n| !n.is_empty());
+    assert_eq!(config.retries, 3);
+    assert_eq!(name, "app");
+
+    // Function paths, renames and type annotations
+    clone!(app.config as checked: Config where Config::is_valid);
+    assert_eq!(checked.retries, 3);
+
+    // Block-bodied closures, whose braces appear in the message
+    clone!(app.name as block where |n| { !n.is_empty() });
+    assert_eq!(block, "app");
+
+    // `ref mut` checks the hidden clone
+    {
+        clone!(ref mut app.name where |n| n == "app");
+        name.push('!');
+        assert_eq!(name, "app!");
+    }
+
+    // A failing predicate panics only when debug assertions are enabled
+    let broken = Config { retries: 0 };
+    let result = std::panic::catch_unwind(|| {
+        clone!(broken where |c| c.is_valid());
+        broken.retries
+    });
+    if cfg!(debug_assertions) {
+        let message = result.unwrap_err();
+        let message = panic_message(&*message);
+        assert!(message.starts_with("clone! post-condition failed for `broken`"));
+
+        let result = std::panic::catch_unwind(|| {
+            clone!(broken as braced where |c| { c.is_valid() });
+            braced.retries
+        });
+        let message = result.unwrap_err();
+        let message = panic_message(&*message);
+        assert!(message.ends_with("{ c.is_valid() }"));
+    } else {
+        assert_eq!(result.unwrap(), 0);
+    }
+}
+
+/// Returns the message of a panic, which is a `&str` when the formatted
+/// arguments are all literals
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> &str {
+    match payload.downcast_ref::<&str>() {
+        Some(message) => message,
+        None => payload.downcast_ref::<String>().unwrap(),
+    }
+}
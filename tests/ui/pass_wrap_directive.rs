@@ -0,0 +1,45 @@
+// Test: `wrap = Type` directive wrapping every clone
+use letclone::clone;
+use std::sync::{Arc, Mutex};
+
+struct Shared {
+    state: Vec<u32>,
+    name: String,
+}
+
+/// A user type with a `new` constructor
+struct Tagged<T> {
+    value: T,
+}
+
+impl<T> Tagged<T> {
+    fn new(value: T) -> Self {
+        Tagged { value }
+    }
+}
+
+fn main() {
+    let shared = Shared {
+        state: vec![1, 2],
+        name: String::from("shared"),
+    };
+
+    // Equivalent to:
+    // let s = Arc::new(shared.state.clone());
+    // let name = Arc::new(shared.name.clone());
+    clone!(wrap = Arc; shared.state as s, shared.name);
+    let _: Arc<Vec<u32>> = s;
+    let _: Arc<String> = name;
+
+    clone!(wrap = Mutex; shared.state);
+    state.lock().unwrap().push(3);
+    assert_eq!(*state.lock().unwrap(), [1, 2, 3]);
+
+    // Custom wrappers, combined with other directives and modifiers
+    clone!(mut wrap = Tagged; shared.name, into_owned std::borrow::Cow::Borrowed("cow") as cow);
+    name.value.push('!');
+    assert_eq!(name.value, "shared!");
+    assert_eq!(cow.value, "cow");
+
+    assert_eq!(shared.state, [1, 2]);
+}
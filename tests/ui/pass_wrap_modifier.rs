@@ -0,0 +1,44 @@
+// Test: `wrap(Type)` modifier wrapping the clone in `Type::new`
+use letclone::clone;
+use std::cell::RefCell;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Node {
+    value: u32,
+    next: Option<Box<Node>>,
+}
+
+fn main() {
+    let node = Node {
+        value: 1,
+        next: None,
+    };
+
+    // Equivalent to: let boxed = Box::new(node.clone());
+    clone!(wrap(Box) node as boxed);
+    let list = Node {
+        value: 0,
+        next: Some(boxed),
+    };
+    assert_eq!(list.next.as_deref(), Some(&node));
+
+    // Field access and `mut`
+    clone!(mut wrap(Box) list.next as next);
+    *next = None;
+    assert!(next.is_none());
+    assert!(list.next.is_some());
+
+    // Each standard wrapper
+    clone!(wrap(Rc) node as rc, wrap(Arc) node as arc, wrap(RefCell) node.value as cell);
+    let _: Rc<Node> = rc;
+    let _: Arc<Node> = arc;
+    *cell.borrow_mut() += 1;
+    assert_eq!(cell.into_inner(), 2);
+
+    clone!(wrap(std::sync::Mutex) node.value as locked);
+    let _: &Mutex<u32> = &locked;
+    assert_eq!(*locked.lock().unwrap(), 1);
+    assert_eq!(node.value, 1);
+}